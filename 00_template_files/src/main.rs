@@ -1,5 +1,23 @@
-use leptos::*;
+use leptos::prelude::*;
+
+#[component]
+fn App() -> impl IntoView {
+    view! { <main></main> }
+}
 
 fn main() {
+    mount_to_body(App);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
 
+        insta::assert_snapshot!(html);
+    }
 }
@@ -1,25 +1,27 @@
-use leptos::*;
+use leptos::prelude::*;
 
 fn main() {
-    mount_to_body(|cx| view! { cx,
+    mount_to_body(|| {
+        view! {
             <Cd initial_value=50/>
             <App/>
             <SimpleCounter initial_value=300/>
-        })
+        }
+    })
 }
 #[component]
-pub fn Cd(cx: Scope, initial_value: i32) -> impl IntoView {
-    let (value, set_value) = create_signal(cx, initial_value);
+pub fn Cd(initial_value: i32) -> impl IntoView {
+    let (value, set_value) = signal(initial_value);
 
-    let clear = move |_| set_value(0);
+    let clear = move |_| set_value.set(0);
     let decrement = move |_| set_value.update(|value| *value -= 1);
     let increment = move |_| set_value.update(|value| *value += 1);
 
-    view! { cx,
-        <div class:red=move || value() % 2 == 1>
+    view! {
+        <div class:red=move || value.get() % 2 == 1>
             <button on:click=clear>"Clear"</button>
             <button on:click=decrement>"-1"</button>
-            <span>"Value: " {move || value().to_string()}</span>
+            <span>"Value: " {move || value.get().to_string()}</span>
             <button on:click=increment>"+1"</button>
         </div>
     }
@@ -27,22 +29,26 @@ pub fn Cd(cx: Scope, initial_value: i32) -> impl IntoView {
 
 /// Quick basic Leptos structure explanation
 #[component]
-pub fn SimpleCounter(cx: Scope, initial_value: i32) -> impl IntoView {
+pub fn SimpleCounter(
+    /// The value the counter starts at.
+    #[prop(optional)]
+    initial_value: i32,
+) -> impl IntoView {
     // create a reactive signal with the initial value
-    let (value, set_value) = create_signal(cx, initial_value);
+    let (value, set_value) = signal(initial_value);
 
     // create event handlers for our buttons
     // note that `value` and `set_value` are `Copy`, so it's super easy to move them into closures
-    let clear = move |_| set_value(0);
+    let clear = move |_| set_value.set(0);
     let decrement = move |_| set_value.update(|value| *value -= 1);
     let increment = move |_| set_value.update(|value| *value += 1);
 
     // this JSX is compiled to an HTML template string for performance
-    view! { cx,
+    view! {
         <div>
             <button on:click=clear>"Clear"</button>
             <button on:click=decrement>"-1"</button>
-            <span>"Value: " {move || value().to_string()}</span>
+            <span>"Value: " {move || value.get().to_string()}</span>
             <button on:click=increment>"+1"</button>
         </div>
     }
@@ -53,14 +59,14 @@ pub fn SimpleCounter(cx: Scope, initial_value: i32) -> impl IntoView {
 // They define a reusable unit of behavior
 #[component]
 /// More detailed basic Leptos structure explanation
-fn App(cx: Scope) -> impl IntoView {
+fn App() -> impl IntoView {
     // here we create a reactive signal
     // and get a (getter, setter) pair
     // signals are the basic unit of change in the framework
-    let (count, set_count) = create_signal(cx, 0);
+    let (count, set_count) = signal(0);
     // the `view` macro is how we define the user interface
     // it uses an HTML-like format that can accept certain Rust values
-    view! { cx,
+    view! {
         <button
             // on:click will run whenever the `click` event fires
             // every event handler is defined as `on:{eventname}`
@@ -83,14 +89,27 @@ fn App(cx: Scope) -> impl IntoView {
         </p>
         <p>
             <strong>"Reactive shorthand: "</strong>
-            // signals are functions, so we can remove the wrapping closure
+            // signals implement `IntoView` themselves, so we can remove the wrapping closure
             {count}
         </p>
         <p>
             <strong>"Not reactive: "</strong>
-            // NOTE: if you write {count()}, this will *not* be reactive
+            // NOTE: if you write {count.get()}, this will *not* be reactive
             // it simply gets the value of count once
-            {count()}
+            {count.get()}
         </p>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+}
@@ -1,8 +1,8 @@
-use leptos::{ev::SubmitEvent, html::Input, *};
+use leptos::{ev::SubmitEvent, html::Input, prelude::*};
 
-/// A demonstration of the use of into_view() and collect_view() functions. 
+/// A demonstration of the use of into_any() and collect_view() functions. 
 #[component]
-fn App(cx: Scope) -> impl IntoView {
+fn App() -> impl IntoView {
     let name_list = vec![
         "igor", "marko", "jani", "rudi", "toni", "franci", "anton", "luka", "matija", "steven",
         "majkolin",
@@ -10,58 +10,59 @@ fn App(cx: Scope) -> impl IntoView {
 
     let names: Vec<_> = uppercase_conversion(&name_list);
 
-    view! { cx,
+    view! {
         <h2>
             <code>"collect_view()"</code>
             " Demo"
         </h2>
         <CollectViewDemo names/>
         <h2>
-            <code>"into_view()"</code>
+            <code>"into_any()"</code>
             " Demo"
         </h2>
-        <IntoViewDemo/>
+        <IntoAnyDemo/>
     }
 }
 
 /// Here we demonstrate how to collect a vector of 'views' into a View, so that the whole vector can be rendered.
 #[component]
-fn CollectViewDemo<IV>(cx: Scope, names: Vec<IV>) -> impl IntoView
+fn CollectViewDemo<IV>(names: Vec<IV>) -> impl IntoView
 where
     IV: IntoView,
 {
-    view! { cx,
+    view! {
         <ul>
             {names
                 .into_iter()
                 .map(|name| {
-                    view! { cx, <li>{name}</li> }
+                    view! { <li>{name}</li> }
                 })
-                .collect_view(cx)}
+                .collect_view()}
         </ul>
     }
 }
 
-/// Here we demonstrate when the use of into_view() might be useful.
+/// Here we demonstrate when the use of into_any() might be useful.
 /// We want to render the BrowserInputPractice component if the button is toggled ON and 
 /// nothing if the button is toggled OFF.
-/// We can achieve that by using the into_view() helper function.
+/// Both branches of the `if` have to be the same type, we can achieve that by erasing their types
+/// with the into_any() helper function.
 #[component]
-fn IntoViewDemo(cx: Scope) -> impl IntoView {
-    let (toggle, set_toggle) = create_signal(cx, false);
+fn IntoAnyDemo() -> impl IntoView {
+    let (toggle, set_toggle) = signal(false);
 
     let click = move |_| set_toggle.update(|boolean| *boolean = !*boolean);
-    view! { cx,
+    view! {
         <button on:click=click>"Click to toggle browser input practice"</button>
-        <p>"Button is toggled: " {move || if toggle() { "On" } else { "Off" }}</p>
+        <p>"Button is toggled: " {move || if toggle.get() { "On" } else { "Off" }}</p>
         {
             move || {
-                if toggle() {
-                    view! {cx, 
+                if toggle.get() {
+                    view! {
                         <BrowserInputPractice/>
-                    }.into_view(cx)
+                    }.into_any()
                 } else {
-                    ().into_view(cx)
+                    ().into_any()
                 }
         }
         }
@@ -69,20 +70,20 @@ fn IntoViewDemo(cx: Scope) -> impl IntoView {
 }
 
 #[component]
-fn BrowserInputPractice(cx: Scope) -> impl IntoView {
-    let (text, set_text) = create_signal(cx, "text".to_string());
-    let input_elem = create_node_ref::<Input>(cx);
+fn BrowserInputPractice() -> impl IntoView {
+    let (text, set_text) = signal("text".to_string());
+    let input_elem = NodeRef::<Input>::new();
 
     let on_submit = move |ev: SubmitEvent| {
         ev.prevent_default();
-        let val = input_elem().expect("<input> to be present").value();
-        set_text(val);
+        let val = input_elem.get().expect("<input> to be present").value();
+        set_text.set(val);
     };
 
-    let num_chars = move || text().chars().count();
+    let num_chars = move || text.get().chars().count();
     let is_odd = move || num_chars() & 1 == 1;
 
-    view! { cx,
+    view! {
         <h2>"Browser Controlled Input practice"</h2>
         <form on:submit=on_submit>
             <input type="text" value=text node_ref=input_elem/>
@@ -97,7 +98,7 @@ fn BrowserInputPractice(cx: Scope) -> impl IntoView {
 // ----------- Regular RUST ----------- //
 
 fn main() {
-    mount_to_body(|cx| view! { cx, <App/> });
+    mount_to_body(|| view! { <App/> });
 }
 
 fn uppercase_conversion(name_list: &Vec<&str>) -> Vec<String> {
@@ -115,3 +116,15 @@ fn uppercase_conversion(name_list: &Vec<&str>) -> Vec<String> {
         .collect()
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+}
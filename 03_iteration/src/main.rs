@@ -1,7 +1,11 @@
-use leptos::*;
+use leptos::{
+    ev::{DragEvent, KeyboardEvent},
+    html::Li,
+    prelude::*,
+};
 
 fn main() {
-    mount_to_body(|cx| view! { cx, <App/> })
+    mount_to_body(|| view! { <App/> })
 }
 
 /// Iteration is a very common task in most applications.
@@ -10,9 +14,9 @@ fn main() {
 /// 1) for mostly-static lists, using Rust iterators
 /// 2) for lists that grow, shrink, or move items, using ```<For/>```
 #[component]
-fn App(cx: Scope) -> impl IntoView {
+fn App() -> impl IntoView {
     let val = vec![1, 2, 3, 4, 5];
-    view! { cx,
+    view! {
         <h1>"Iteration"</h1>
         <h2>"Static Items and Static List"</h2>
         <p>"Everything is static"</p>
@@ -27,22 +31,22 @@ fn App(cx: Scope) -> impl IntoView {
 }
 
 /// Shows how to use vectors to render static lists, also demonstrates the use of collect_view() helper method
-/// that allows you to collect any iterator of ```T: IntoView``` into ```Vec<View>``` otherwise you would have to use 
+/// that allows you to collect any iterator of ```T: IntoView``` into a view, otherwise you would have to use 
 /// turbofish syntax with collect() method, this is demonstrated in the StaticList component.
 #[component]
-fn StItems<IV>(cx: Scope, val: Vec<IV>) -> impl IntoView
+fn StItems<IV>(val: Vec<IV>) -> impl IntoView
 where
     IV: IntoView + Clone,
 {
-    view! { cx,
+    view! {
         <p>{val.clone()}</p>
         <ul>
             {val
                 .into_iter()
                 .map(|n| {
-                    view! { cx, <li>{n}</li> }
+                    view! { <li>{n}</li> }
                 })
-                .collect_view(cx)}
+                .collect_view()}
         </ul>
     }
 }
@@ -52,19 +56,18 @@ where
 /// This however is very inefficient as it rerenders everything everytime anything changes
 #[component]
 fn StaticList(
-    cx: Scope,
     /// How many counters to include in this list.
     length: usize,
 ) -> impl IntoView {
     // create counter signals that start at incrementing numbers
-    let counters = (1..=length).map(|idx| create_signal(cx, idx));
+    let counters = (1..=length).map(|idx| signal(idx));
 
     // when you have a list that doesn't change, you can
     // manipulate it using ordinary Rust iterators
     // and collect it into a Vec<_> to insert it into the DOM
     let counter_buttons = counters
         .map(|(count, set_count)| {
-            view! { cx,
+            view! {
                 <li>
                     <button on:click=move |_| set_count.update(|n| *n += 1)>{count}</button>
                 </li>
@@ -75,14 +78,13 @@ fn StaticList(
     // Note that if `counter_buttons` were a reactive list
     // and its value changed, this would be very inefficient:
     // it would rerender every row every time the list changed.
-    view! { cx, <ul>{counter_buttons}</ul> }
+    view! { <ul>{counter_buttons}</ul> }
 }
 
-/// A list of counters that allows you to add or
-/// remove counters.
+/// A list of counters that allows you to add, remove and reorder counters.
+/// Rows can be dragged onto each other, or moved with Alt+ArrowUp and Alt+ArrowDown.
 #[component]
 fn DynamicList(
-    cx: Scope,
     /// The number of counters to begin with.
     initial_length: usize,
 ) -> impl IntoView {
@@ -100,17 +102,17 @@ fn DynamicList(
     // we generate an initial list as in <StaticList/>
     // but this time we include the ID along with the signal
     let initial_counters = (0..initial_length)
-        .map(|id| (id, create_signal(cx, id + 1)))
+        .map(|id| (id, signal(id + 1)))
         .collect::<Vec<_>>();
 
     // now we store that initial list in a signal
     // this way, we'll be able to modify the list over time,
     // adding and removing counters, and it will change reactively
-    let (counters, set_counters) = create_signal(cx, initial_counters);
+    let (counters, set_counters) = signal(initial_counters);
 
     let add_counter = move |_| {
         // create a signal for the new counter
-        let sig = create_signal(cx, next_counter_id + 1);
+        let sig = signal(next_counter_id + 1);
         // add this counter to the list of counters
         set_counters.update(move |counters| {
             // since `.update()` gives us `&mut T`
@@ -121,29 +123,78 @@ fn DynamicList(
         next_counter_id += 1;
     };
 
+    // the id of the row that is being dragged around, if any
+    let (dragged, set_dragged) = signal(None::<usize>);
+
     // The <For/> component is central here
     // This allows for efficient, key list rendering
     //
     // `each` takes any function that returns an iterator
-    // this should usually be a signal or derived signal
+    // this should usually read a signal, or be a derived signal
     // if it's not reactive, just render a Vec<_> instead of <For/>
     //
     // the key should be unique and stable for each row
     // using an index is usually a bad idea, unless your list
     // can only grow, because moving items around inside the list
     // means their indices will change and they will all rerender
-    // the view function receives each item from your `each` iterator
+    // the children function receives each item from your `each` iterator
     // and returns a view
-    view! { cx,
+    //
+    // moving a row only moves its key, so <For/> moves the existing <li>
+    // and the counter inside it keeps its signal, and therefore its count
+    view! {
         <div>
             <button on:click=add_counter>"Add Counter"</button>
             <ul>
                 <For
-                    each=counters
+                    each=move || counters.get()
                     key=|counter| counter.0
-                    view=move |cx, (id, (count, set_count))| {
-                        view! { cx,
-                            <li>
+                    children=move |(id, (count, set_count))| {
+                        let row = NodeRef::<Li>::new();
+                        let on_keydown = move |ev: KeyboardEvent| {
+                            let by = match ev.key().as_str() {
+                                "ArrowUp" if ev.alt_key() => -1,
+                                "ArrowDown" if ev.alt_key() => 1,
+                                _ => return,
+                            };
+                            ev.prevent_default();
+                            set_counters.update(|counters| move_by(counters, id, by));
+                            // the DOM is updated after this handler, and a moved element loses focus
+                            request_animation_frame(move || {
+                                if let Some(row) = row.get_untracked() {
+                                    _ = row.focus();
+                                }
+                            });
+                        };
+                        let on_dragstart = move |ev: DragEvent| {
+                            set_dragged.set(Some(id));
+                            if let Some(data) = ev.data_transfer() {
+                                data.set_effect_allowed("move");
+                                // some browsers don't start a drag without any data
+                                _ = data.set_data("text/plain", &id.to_string());
+                            }
+                        };
+                        // the default of `dragover` is to refuse the drop
+                        let on_dragover = move |ev: DragEvent| ev.prevent_default();
+                        let on_drop = move |ev: DragEvent| {
+                            ev.prevent_default();
+                            if let Some(from) = dragged.get_untracked() {
+                                set_counters.update(|counters| move_to(counters, from, id));
+                            }
+                            set_dragged.set(None);
+                        };
+                        view! {
+                            <li
+                                node_ref=row
+                                tabindex="0"
+                                draggable="true"
+                                class:dragging=move || dragged.get() == Some(id)
+                                on:keydown=on_keydown
+                                on:dragstart=on_dragstart
+                                on:dragover=on_dragover
+                                on:drop=on_drop
+                                on:dragend=move |_| set_dragged.set(None)
+                            >
                                 <button on:click=move |_| set_count.update(|n| *n += 1)>{count}</button>
                                 <button on:click=move |_| {
                                     set_counters
@@ -157,3 +208,132 @@ fn DynamicList(
         </div>
     }
 }
+
+/// Moves the item with id `id` `by` places up (negative) or down (positive), stopping at the ends of the list.
+fn move_by<T>(list: &mut Vec<(usize, T)>, id: usize, by: isize) {
+    if let Some(from) = list.iter().position(|(item_id, _)| *item_id == id) {
+        let to = from.saturating_add_signed(by).min(list.len() - 1);
+        let item = list.remove(from);
+        list.insert(to, item);
+    }
+}
+
+/// Moves the item with id `from` to where the item with id `to` is now, like a drop does.
+fn move_to<T>(list: &mut Vec<(usize, T)>, from: usize, to: usize) {
+    let position = |id| list.iter().position(|(item_id, _)| *item_id == id);
+    if let (Some(from), Some(to)) = (position(from), position(to)) {
+        let item = list.remove(from);
+        list.insert(to, item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+
+    fn ids(list: &[(usize, ())]) -> Vec<usize> {
+        list.iter().map(|(id, _)| *id).collect()
+    }
+
+    #[test]
+    fn move_by_stops_at_the_ends() {
+        let mut list = vec![(0, ()), (1, ()), (2, ())];
+
+        move_by(&mut list, 1, -1);
+        assert_eq!(ids(&list), [1, 0, 2]);
+        move_by(&mut list, 1, -1);
+        assert_eq!(ids(&list), [1, 0, 2]);
+        move_by(&mut list, 1, 5);
+        assert_eq!(ids(&list), [0, 2, 1]);
+    }
+
+    #[test]
+    fn move_to_takes_the_place_of_the_target() {
+        let mut list = vec![(0, ()), (1, ()), (2, ())];
+
+        move_to(&mut list, 0, 2);
+        assert_eq!(ids(&list), [1, 2, 0]);
+        move_to(&mut list, 0, 1);
+        assert_eq!(ids(&list), [0, 1, 2]);
+        // a row that was removed in the meantime is ignored
+        move_to(&mut list, 7, 1);
+        assert_eq!(ids(&list), [0, 1, 2]);
+    }
+}
+
+// These tests need a browser, see the README for how to run them.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use super::*;
+    use components::testing::{click, count, drag_and_drop, mount, press, text, texts};
+    use leptos::task::tick;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn dynamic_list_adds_counters() {
+        let root = mount(|| view! { <DynamicList initial_length=2/> });
+        assert_eq!(count(&root, "li"), 2);
+
+        click(&root, "Add Counter");
+        tick().await;
+
+        assert_eq!(count(&root, "li"), 3);
+        assert_eq!(text(&root, "li:last-child button"), "3");
+    }
+
+    #[wasm_bindgen_test]
+    async fn dynamic_list_counts_and_removes() {
+        let root = mount(|| view! { <DynamicList initial_length=2/> });
+
+        click(&root, "1");
+        tick().await;
+        assert_eq!(text(&root, "li:first-child button"), "2");
+
+        click(&root, "Remove");
+        tick().await;
+        assert_eq!(count(&root, "li"), 1);
+        // the counter that was left is the second one, untouched
+        assert_eq!(text(&root, "li button"), "2");
+    }
+
+    #[wasm_bindgen_test]
+    async fn counters_keep_their_count_when_moved_with_the_keyboard() {
+        let root = mount(|| view! { <DynamicList initial_length=3/> });
+
+        click(&root, "3");
+        tick().await;
+        press(&root, "li:last-child", "Alt+ArrowUp");
+        tick().await;
+        assert_eq!(texts(&root, "li > button:first-child"), ["1", "4", "2"]);
+
+        // it is still the same counter, so it counts on from where it was
+        click(&root, "4");
+        tick().await;
+        assert_eq!(texts(&root, "li > button:first-child"), ["1", "5", "2"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn counters_keep_their_count_when_dragged() {
+        let root = mount(|| view! { <DynamicList initial_length=3/> });
+
+        for _ in 0..5 {
+            click(&root, &text(&root, "li:first-child button"));
+            tick().await;
+        }
+        drag_and_drop(&root, "li:first-child", "li:last-child");
+        tick().await;
+
+        assert_eq!(texts(&root, "li > button:first-child"), ["2", "3", "6"]);
+        assert_eq!(count(&root, "li.dragging"), 0);
+    }
+}
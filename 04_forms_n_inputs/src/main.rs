@@ -1,22 +1,45 @@
-use leptos::{ev::SubmitEvent, html::Input, *};
+use components::{
+    form::{max_len, min_len, pattern, range, required},
+    FieldError, FormState,
+};
+use gloo_timers::future::TimeoutFuture;
+use leptos::{ev::SubmitEvent, html::Input, prelude::*};
 
 fn main() {
-    mount_to_body(|cx| {
-        view! { cx,
-            <h2>"Controlled Input"</h2>
-            <ContInput/>
-            <h2>"Uncontrolled Input"</h2>
-            <UncontInput/>
-        }
-    })
+    mount_to_body(|| view! { <App/> })
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <h2>"Controlled Input"</h2>
+        <ContInput/>
+        <h2>"Uncontrolled Input"</h2>
+        <UncontInput/>
+    }
 }
 
 /// In a "controlled input" the framework controls the state of the input element.
 /// On every `input` event, it updates a local signal that holds the current state, which in turn
 /// updates the `value` prop of the input.
 #[component]
-fn ContInput(cx: Scope) -> impl IntoView {
-    let (name, set_name) = create_signal(cx, "Controlled".to_string());
+fn ContInput() -> impl IntoView {
+    // the field holds the text of the input, and knows whether it is valid
+    let form = FormState::new();
+    let name = form
+        .field::<String>("Controlled")
+        .validate(required())
+        .validate(min_len(2))
+        .validate(max_len(20))
+        // pretend to ask a server whether the name is still free
+        .validate_async(|name: String| async move {
+            TimeoutFuture::new(300).await;
+            if name.eq_ignore_ascii_case("admin") {
+                Err(format!("{name} is taken"))
+            } else {
+                Ok(())
+            }
+        });
 
     // event_target_value is a Leptos helper function it functions the same way as event.target.value
     // in JavaScript, but smooths out some of the typecasting necessary to make this work in Rust
@@ -28,13 +51,17 @@ fn ContInput(cx: Scope) -> impl IntoView {
     // only updates the input up to the point that you begin typing.
     // The `value` property continues updating the input after that.
     // That's why we use `prop:value=` not `value=`
-    view! { cx,
+    //
+    // The field counts as touched once the input loses focus, only then does the error show up.
+    view! {
         <input
             type="text"
-            on:input=move |ev| { set_name(event_target_value(&ev)) }
-            prop:value=name
+            on:input=move |ev| name.set_text(event_target_value(&ev))
+            on:blur=move |_| name.touch()
+            prop:value=move || name.text()
         />
-        <p>"Name is: " {name}</p>
+        <FieldError field=name/>
+        <p>"Name is: " {move || name.text()}</p>
     }
 }
 
@@ -42,31 +69,63 @@ fn ContInput(cx: Scope) -> impl IntoView {
 /// Rather than continuously updating a signal to hold its value, we use a NodeRef to access the
 /// input once when we want to get its value.
 #[component]
-fn UncontInput(cx: Scope) -> impl IntoView {
-    let (name, set_name) = create_signal(cx, "Uncontrolled".to_string());
-    let input_elem: NodeRef<Input> = create_node_ref(cx);
+fn UncontInput() -> impl IntoView {
+    // the fields only learn about the inputs when the form is submitted
+    let form = FormState::new();
+    let name = form
+        .field::<String>("Uncontrolled")
+        .validate(required())
+        .validate(pattern("^[A-Za-z ]*$", "can only contain letters and spaces"));
+    // a typed field, the text of the input is parsed into a `u8`
+    let age = form.field::<u8>(30).validate(range(1..=120));
+    // what is shown below the form, only updated by a valid submit
+    let (submitted, set_submitted) = signal(String::from("Uncontrolled"));
+    let input_elem: NodeRef<Input> = NodeRef::new();
+    let age_elem: NodeRef<Input> = NodeRef::new();
 
     let on_submit = move |ev: SubmitEvent| {
         // stop the page from reloading
         ev.prevent_default();
 
-        // to access the DOM node stored in the NodeRef, we can simply call it as a function
-        // (or using `.get()` if on stable)
-        let val = input_elem()
+        // to access the DOM node stored in the NodeRef, we call `.get()` on it
+        let val = input_elem
+            .get()
             // event handlers can only fire after the view is mounted to the DOM,
             // so we know the `NodeRef` will be `Some`
             .expect("<input> to exist")
-            // `NodeRef` implements `Deref` for the DOM element type,
+            // the `NodeRef` hands us the DOM element type itself,
             // we can call `HtmlInputElement::value()` to get the current value of the input.
             .value();
-        set_name(val);
+        name.set_text(val);
+        age.set_text(age_elem.get().expect("<input> to exist").value());
+
+        // touches every field, so their errors show up
+        if form.submit() {
+            set_submitted.set(format!("{} ({})", name.text(), age.text()));
+        }
     };
 
-    view! { cx,
+    view! {
         <form on:submit=on_submit>
-            <input type="text" value=name node_ref=input_elem/>
+            <input type="text" value=move || name.text() node_ref=input_elem/>
+            <FieldError field=name/>
+            <input type="number" value=move || age.text() node_ref=age_elem/>
+            <FieldError field=age/>
             <input type="submit" value="Submit"/>
         </form>
-        <p>"Name is: " {name}</p>
+        <p>"Name is: " {submitted}</p>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
     }
 }
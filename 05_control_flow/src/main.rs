@@ -1,28 +1,39 @@
-use leptos::*;
+use components::{RowHeight, VirtualList};
+use leptos::prelude::*;
 
 fn main() {
-    mount_to_body(|cx| {
-        view! { cx, <App/> }
+    mount_to_body(|| {
+        view! { <App/> }
     })
 }
 
 /// An example of a simple static list, plus a demonstration of using various control flow constructs.
+/// The list only puts the rows you can see into the page, see `VirtualList` in the components crate.
 #[component]
-fn App(cx: Scope) -> impl IntoView {
-    let vals = (0..100).map(|idx| create_signal(cx, idx));
-
-    let paragraphs = vals
-        .map(|(value, _)| {
-            view! { cx,
-                <IfControl value/>
-                <OptionControl value/>
-                <MatchControl value/>
-                <Conversion value/>
+fn App() -> impl IntoView {
+    let vals: Vec<_> = (0..100).map(|n| (n, signal(n).0)).collect();
+
+    let paragraphs = view! {
+        <VirtualList
+            each=move || vals.clone()
+            key=|(n, _)| *n
+            view=|(_, value)| {
+                view! {
+                    <div>
+                        <IfControl value/>
+                        <OptionControl value/>
+                        <MatchControl value/>
+                        <Conversion value/>
+                    </div>
+                }
             }
-        })
-        .collect_view(cx);
+            // the rows are different heights, depending on what `Conversion` renders
+            row_height=RowHeight::Measured { estimate: 90.0 }
+            height=600.0
+        />
+    };
 
-    view! { cx,
+    view! {
             <main>
                 <div>
                     <h1>"My Examples"</h1>
@@ -46,19 +57,19 @@ fn App(cx: Scope) -> impl IntoView {
 /// and children to be rendered if 'when' is true.
 /// There is some overhead though, so for a very simple node a 'move || if ...' will be more efficient.
 #[component]
-fn DemoShow(cx: Scope) -> impl IntoView {
-    let (val, set_val) = create_signal(cx, 0);
+fn DemoShow() -> impl IntoView {
+    let (val, set_val) = signal(0);
 
     let increment = move |_| set_val.update(|val| *val += 1);
 
-    view! { cx,
+    view! {
         <button on:click=increment>"Click for +1"</button>
         <p>{val}</p>
         <div class="showtext-div">
             <Show
-                when=move || val() < 5
-                fallback=|_| {
-                    view! { cx, "Even more EXPENSIVE! X > 5!" }
+                when=move || val.get() < 5
+                fallback=|| {
+                    view! { "Even more EXPENSIVE! X > 5!" }
                 }
             >
                 "X < 5. Imagine this is a really expensive paragraph!"
@@ -74,57 +85,56 @@ fn DemoShow(cx: Scope) -> impl IntoView {
 /// Note the syntax for 'is_odd' closure, and the message syntax:
 /// `move || if is_odd() { "Odd" } else { "Even" }`
 #[component]
-fn IfControl(cx: Scope, value: ReadSignal<i32>) -> impl IntoView {
-    let is_odd = move || value() & 1 == 1;
+fn IfControl(value: ReadSignal<i32>) -> impl IntoView {
+    let is_odd = move || value.get() & 1 == 1;
 
     let msg = move || if is_odd() { "Odd" } else { "Even" };
-    view! { cx, <p class="if-par">{msg}</p> }
+    view! { <p class="if-par">{msg}</p> }
 }
 
 /// This example shows how you can use an `Option<impl IntoView>` to control the program flow.
 #[component]
-fn OptionControl(cx: Scope, value: ReadSignal<i32>) -> impl IntoView {
-    let is_odd = move || value() & 1 == 1;
+fn OptionControl(value: ReadSignal<i32>) -> impl IntoView {
+    let is_odd = move || value.get() & 1 == 1;
 
-    let msg = is_odd().then(|| "Ding ding ding!");
-    view! { cx, <p class="option-par">{msg}</p> }
+    let msg = is_odd().then_some("Ding ding ding!");
+    view! { <p class="option-par">{msg}</p> }
 }
 
 /// This example shows how you can use 'match statements' to control the program flow.
 #[component]
-fn MatchControl(cx: Scope, value: ReadSignal<i32>) -> impl IntoView {
-    let is_odd = move || value() & 1 == 1;
+fn MatchControl(value: ReadSignal<i32>) -> impl IntoView {
+    let is_odd = move || value.get() & 1 == 1;
 
-    let msg = match value() {
+    let msg = match value.get() {
         0 => "Nič",
         1 => "Ena", 
         _n if is_odd() => "Liho", 
         _ => "Sodo"
     };
-    view! { cx, <p class="match-par">{msg}</p> }
+    view! { <p class="match-par">{msg}</p> }
 }
 
 /// This example shows how you can return multiple different element types from different branches
 /// of a condition.
-/// 1. If you have multiple HtmlElement types, convert them to HtmlElement<AnyElement> with '.into_any()' 
-/// 2. If you have a variety of view types that are not all HtmlElement, convert them to Views
-///    with '.into_view(cx)'.
+/// Every branch has to return the same type, so we erase the types of the different elements
+/// with '.into_any()', which turns any view into an AnyView.
 #[component]
-fn Conversion(cx: Scope, value: ReadSignal<i32>) -> impl IntoView {
-    let is_odd = move || value() & 1 == 1;
+fn Conversion(value: ReadSignal<i32>) -> impl IntoView {
+    let is_odd = move || value.get() & 1 == 1;
 
-    view! { cx,
+    view! {
         {move || match is_odd() {
-            true if value() == 1 => {
-                view! { cx, <pre>"One"</pre> }
+            true if value.get() == 1 => {
+                view! { <pre>"One"</pre> }
                     .into_any()
             }
-            false if value() == 2 => {
-                view! { cx, <p>"Two"</p> }
+            false if value.get() == 2 => {
+                view! { <p>"Two"</p> }
                     .into_any()
             }
             _ => {
-                view! { cx, <textarea>{value()}</textarea> }
+                view! { <textarea>{value.get()}</textarea> }
                     .into_any()
             }
         }}
@@ -133,12 +143,12 @@ fn Conversion(cx: Scope, value: ReadSignal<i32>) -> impl IntoView {
 
 /// A book example
 #[component]
-fn LeptosBookExample(cx: Scope) -> impl IntoView {
-    let (value, set_value) = create_signal(cx, 0);
-    let is_odd = move || value() & 1 == 1;
+fn LeptosBookExample() -> impl IntoView {
+    let (value, set_value) = signal(0);
+    let is_odd = move || value.get() & 1 == 1;
     let odd_text = move || if is_odd() { Some("How odd!") } else { None };
 
-    view! { cx,
+    view! {
         <h1>"Control Flow"</h1>
 
         // Simple UI to update and show a value
@@ -185,31 +195,43 @@ fn LeptosBookExample(cx: Scope) -> impl IntoView {
         //    needed. This makes it more efficient in many cases
         //    than a {move || if ...} block
         <Show when=is_odd
-            fallback=|cx| view! { cx, <p>"Even steven"</p> }
+            fallback=|| view! { <p>"Even steven"</p> }
         >
             <p>"Oddment"</p>
         </Show>
 
         // d. Because `bool::then()` converts a `bool` to
         //    `Option`, you can use it to create a show/hide toggled
-        {move || is_odd().then(|| view! { cx, <p>"Oddity!"</p> })}
+        {move || is_odd().then(|| view! { <p>"Oddity!"</p> })}
 
         <h2>"Converting between Types"</h2>
         // e. Note: if branches return different types,
         //    you can convert between them with
-        //    `.into_any()` (for different HTML element types)
-        //    or `.into_view(cx)` (for all view types)
+        //    `.into_any()`, which works for every view type
         {move || match is_odd() {
-            true if value() == 1 => {
+            true if value.get() == 1 => {
                 // <pre> returns HtmlElement<Pre>
-                view! { cx, <pre>"One"</pre> }.into_any()
+                view! { <pre>"One"</pre> }.into_any()
             },
-            false if value() == 2 => {
+            false if value.get() == 2 => {
                 // <p> returns HtmlElement<P>
                 // so we convert into a more generic type
-                view! { cx, <p>"Two"</p> }.into_any()
+                view! { <p>"Two"</p> }.into_any()
             }
-            _ => view! { cx, <textarea>{value()}</textarea> }.into_any()
+            _ => view! { <textarea>{value.get()}</textarea> }.into_any()
         }}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+}
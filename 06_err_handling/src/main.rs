@@ -1,14 +1,14 @@
-use leptos::*;
+use leptos::prelude::*;
 
 fn main() {
-    mount_to_body(|cx| {
-        view! { cx, <App/> }
+    mount_to_body(|| {
+        view! { <App/> }
     });
 }
 
 #[component]
-fn App(cx: Scope) -> impl IntoView {
-    view! { cx,
+fn App() -> impl IntoView {
+    view! {
         <NumericInput/>
         <BookExample/>
     }
@@ -19,30 +19,30 @@ fn App(cx: Scope) -> impl IntoView {
 /// Note that in this component we dont handle the error, instead if error gets outputted
 /// we just don't render the user input.
 #[component]
-fn NumericInput(cx: Scope) -> impl IntoView {
-    let (val, set_val) = create_signal(cx, Ok(0));
+fn NumericInput() -> impl IntoView {
+    let (val, set_val) = signal(Ok(0));
 
     // when input changes, try to parse a number from the input
-    let on_input = move |ev| set_val(event_target_value(&ev).parse::<i32>());
+    let on_input = move |ev| set_val.set(event_target_value(&ev).parse::<i32>());
 
-    view! { cx,
+    view! {
         <h2>"No error handling"</h2>
         <label>
             "Type a number (or not!)" <input type="number" on:input=on_input/>
-            <p>"You entered " <strong>{val}</strong></p>
+            <p>"You entered " <strong>{move || val.get().ok()}</strong></p>
         </label>
     }
 }
 
 /// To actually handle the errors you can use the <ErrorBoundary/> component.
 #[component]
-fn BookExample(cx: Scope) -> impl IntoView {
-    let (value, set_value) = create_signal(cx, Ok(0));
+fn BookExample() -> impl IntoView {
+    let (value, set_value) = signal(Ok(0));
 
     // when input changes, try to parse a number from the input
-    let on_input = move |ev| set_value(event_target_value(&ev).parse::<i32>());
+    let on_input = move |ev| set_value.set(event_target_value(&ev).parse::<i32>());
 
-    view! { cx,
+    view! {
         <h2>"<ErrorBoundary/> handling"</h2>
         <label>
             "Type a number (or something that's not a number!)"
@@ -53,15 +53,15 @@ fn BookExample(cx: Scope) -> impl IntoView {
             // <ErrorBoundary/> will be displayed.
             <ErrorBoundary
                 // the fallback receives a signal containing current errors
-                fallback=|cx, errors| view! { cx,
+                fallback=|errors| view! {
                     <div class="error">
                         <p>"Not a number! Errors: "</p>
                         // we can render a list of errors
                         // as strings, if we'd like
                         <ul>
-                            {move || errors()
+                            {move || errors.get()
                                 .into_iter()
-                                .map(|(_, e)| view! { cx, <li>{e.to_string()}</li>})
+                                .map(|(_, e)| view! { <li>{e.to_string()}</li>})
                                 .collect::<Vec<_>>()
                             }
                         </ul>
@@ -81,3 +81,56 @@ fn BookExample(cx: Scope) -> impl IntoView {
         </label>
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+}
+
+// These tests need a browser, see the README for how to run them.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use super::*;
+    use components::testing::{mount, text, type_into};
+    use leptos::task::tick;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn book_example_shows_the_number() {
+        let root = mount(|| view! { <BookExample/> });
+        assert_eq!(text(&root, "strong"), "0");
+
+        type_into(&root, "input", "42");
+        tick().await;
+
+        assert_eq!(text(&root, "strong"), "42");
+        assert!(root.query_selector(".error").unwrap().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn book_example_falls_back_on_errors() {
+        let root = mount(|| view! { <BookExample/> });
+
+        // a number input has no value at all when it holds something that isn't a number
+        type_into(&root, "input", "");
+        tick().await;
+
+        assert!(text(&root, ".error").starts_with("Not a number!"));
+
+        type_into(&root, "input", "7");
+        tick().await;
+        assert!(root.query_selector(".error").unwrap().is_none());
+        assert_eq!(text(&root, "strong"), "7");
+    }
+}
@@ -1,10 +1,10 @@
-use context::ContextApp;
 use book_app::BookApp;
-use leptos::{ev::MouseEvent, *};
+use context::ContextApp;
+use leptos::{ev::MouseEvent, prelude::*};
 
 fn main() {
-    mount_to_body(|cx| {
-        view! { cx,
+    mount_to_body(|| {
+        view! {
             <App/>
             <ContextApp/>
             <BookApp/>
@@ -19,16 +19,16 @@ fn main() {
 /// 3. Use an Event Listener
 /// 4. Providing a Context -> Described in `<ContextApp/>` documentation comments.
 #[component]
-pub fn App(cx: Scope) -> impl IntoView {
+pub fn App() -> impl IntoView {
     // Create different signals for each button for demonstration purposes
-    let (toggled_a, set_toggled_a) = create_signal(cx, false);
-    let (toggled_b, set_toggled_b) = create_signal(cx, false);
-    let (toggled_c, set_toggled_c) = create_signal(cx, false);
+    let (toggled_a, set_toggled_a) = signal(false);
+    let (toggled_b, set_toggled_b) = signal(false);
+    let (toggled_c, set_toggled_c) = signal(false);
 
     let click_callback = move |_| set_toggled_b.update(|val| *val = !*val);
     let click_event_listener = move |_| set_toggled_c.update(|val| *val = !*val);
 
-    view! { cx,
+    view! {
         <p>"PassWriteSignal toggled? " {toggled_a}</p>
         <PassWriteSignal setter=set_toggled_a/>
 
@@ -46,10 +46,10 @@ pub fn App(cx: Scope) -> impl IntoView {
 /// This pattern is simple, but you should be careful with it: passing around a WriteSignal can
 /// make it hard to reason about your code, ie. it makes it easy to write spaghetti code.
 #[component]
-pub fn PassWriteSignal(cx: Scope, setter: WriteSignal<bool>) -> impl IntoView {
+pub fn PassWriteSignal(setter: WriteSignal<bool>) -> impl IntoView {
     let click = move |_| setter.update(|value| *value = !*value);
 
-    view! { cx, <button on:click=click>"Toggle"</button> }
+    view! { <button on:click=click>"Toggle"</button> }
 }
 
 /// 2. Using a Callback
@@ -57,11 +57,11 @@ pub fn PassWriteSignal(cx: Scope, setter: WriteSignal<bool>) -> impl IntoView {
 /// This has the advantage of keeping local state local. But it also means the logic to mutate that
 /// signal needs to exist up in <App/> not inside this component.
 #[component]
-pub fn UseCallback<F>(cx: Scope, on_click: F) -> impl IntoView
+pub fn UseCallback<F>(on_click: F) -> impl IntoView
 where
     F: Fn(MouseEvent) + 'static,
 {
-    view! { cx, <button on:click=on_click>"Toggle"</button> }
+    view! { <button on:click=on_click>"Toggle"</button> }
 }
 
 /// 3. Using an Event Listener
@@ -76,12 +76,12 @@ where
 /// elements you're rendering in the components. For more complex logic that doesn't map directly
 /// onto an element you should use the callback pattern.
 #[component]
-pub fn UseEventListener(cx: Scope) -> impl IntoView {
-    view! { cx, <button>"Toggle"</button> }
+pub fn UseEventListener() -> impl IntoView {
+    view! { <button>"Toggle"</button> }
 }
 
 mod context {
-    use leptos::*;
+    use leptos::prelude::*;
 
     /// 4. Providing a contex
     /// If you have a deeply nested context tree you can't simply pass your 'WriteSignal' to
@@ -94,25 +94,25 @@ mod context {
     ///
     /// Here the same caveats apply as in the 1st pattern: passing a 'WriteSignal' should be done
     /// with caution, as it allows you to mutate state from arbitrary parts of your code.
-    /// 
+    ///
     /// There are no performance downsides to this approach. Because what is passed is a
     /// fine-grained reactive signal, *nothing happens* in the intervening components (`<Content/>`
     /// and `<Layout/>`) when you update it.
     #[component]
-    pub fn ContextApp(cx: Scope) -> impl IntoView {
-        let (toggled, set_toggled) = create_signal(cx, false);
+    pub fn ContextApp() -> impl IntoView {
+        let (toggled, set_toggled) = signal(false);
 
-        provide_context(cx, set_toggled);
+        provide_context(set_toggled);
 
-        view! { cx,
+        view! {
             <Layout/>
             <p>"Toggled? " {toggled}</p>
         }
     }
 
     #[component]
-    pub fn Layout(cx: Scope) -> impl IntoView {
-        view! { cx,
+    pub fn Layout() -> impl IntoView {
+        view! {
             <header>
                 <h2>"Context"</h2>
             </header>
@@ -123,8 +123,8 @@ mod context {
     }
 
     #[component]
-    pub fn Content(cx: Scope) -> impl IntoView {
-        view! { cx,
+    pub fn Content() -> impl IntoView {
+        view! {
             <div class="content">
                 <ButtonD/>
             </div>
@@ -132,18 +132,16 @@ mod context {
     }
 
     #[component]
-    pub fn ButtonD(cx: Scope) -> impl IntoView {
-        let setter: WriteSignal<bool> = use_context(cx).expect("to have found the setter provided");
+    pub fn ButtonD() -> impl IntoView {
+        let setter: WriteSignal<bool> = use_context().expect("to have found the setter provided");
 
         let click_on_button = move |_| setter.update(|val| *val = !*val);
-        view! {cx,
-            <button on:click=click_on_button>"Toggle"</button>
-        }
+        view! { <button on:click=click_on_button>"Toggle"</button> }
     }
 }
 
 mod book_app {
-    use leptos::{ev::MouseEvent, *};
+    use leptos::{ev::MouseEvent, prelude::*};
 
     // This highlights four different ways that child components can communicate
     // with their parent:
@@ -158,20 +156,19 @@ mod book_app {
     struct SmallcapsContext(WriteSignal<bool>);
 
     #[component]
-    pub fn BookApp(cx: Scope) -> impl IntoView {
+    pub fn BookApp() -> impl IntoView {
         // just some signals to toggle three classes on our <p>
-        let (red, set_red) = create_signal(cx, false);
-        let (right, set_right) = create_signal(cx, false);
-        let (italics, set_italics) = create_signal(cx, false);
-        let (smallcaps, set_smallcaps) = create_signal(cx, false);
+        let (red, set_red) = signal(false);
+        let (right, set_right) = signal(false);
+        let (italics, set_italics) = signal(false);
+        let (smallcaps, set_smallcaps) = signal(false);
 
         // the newtype pattern isn't *necessary* here but is a good practice
         // it avoids confusion with other possible future `WriteSignal<bool>` contexts
         // and makes it easier to refer to it in ButtonC
-        provide_context(cx, SmallcapsContext(set_smallcaps));
+        provide_context(SmallcapsContext(set_smallcaps));
 
         view! {
-            cx,
             <hr/>
             <div>
                 <h2>"Book example"</h2>
@@ -205,38 +202,24 @@ mod book_app {
     /// Button A receives a signal setter and updates the signal itself
     #[component]
     pub fn ButtonA(
-        cx: Scope,
         /// Signal that will be toggled when the button is clicked.
         setter: WriteSignal<bool>,
     ) -> impl IntoView {
         view! {
-            cx,
-            <button
-                on:click=move |_| setter.update(|value| *value = !*value)
-            >
-                "Toggle Red"
-            </button>
+            <button on:click=move |_| setter.update(|value| *value = !*value)>"Toggle Red"</button>
         }
     }
 
     /// Button B receives a closure
     #[component]
     pub fn ButtonB<F>(
-        cx: Scope,
         /// Callback that will be invoked when the button is clicked.
         on_click: F,
     ) -> impl IntoView
     where
         F: Fn(MouseEvent) + 'static,
     {
-        view! {
-            cx,
-            <button
-                on:click=on_click
-            >
-                "Toggle Right"
-            </button>
-        }
+        view! { <button on:click=on_click>"Toggle Right"</button> }
 
         // just a note: in an ordinary function ButtonB could take on_click: impl Fn(MouseEvent) + 'static
         // and save you from typing out the generic
@@ -254,9 +237,8 @@ mod book_app {
     /// Button C is a dummy: it renders a button but doesn't handle
     /// its click. Instead, the parent component adds an event listener.
     #[component]
-    pub fn ButtonC(cx: Scope) -> impl IntoView {
+    pub fn ButtonC() -> impl IntoView {
         view! {
-            cx,
             <button>
                 "Toggle Italics"
             </button>
@@ -266,16 +248,26 @@ mod book_app {
     /// Button D is very similar to Button A, but instead of passing the setter as a prop
     /// we get it from the context
     #[component]
-    pub fn ButtonD(cx: Scope) -> impl IntoView {
-        let setter = use_context::<SmallcapsContext>(cx).unwrap().0;
+    pub fn ButtonD() -> impl IntoView {
+        let setter = use_context::<SmallcapsContext>().unwrap().0;
 
         view! {
-            cx,
-            <button
-                on:click=move |_| setter.update(|value| *value = !*value)
-            >
-                "Toggle Small Caps"
-            </button>
+            <button on:click=move |_| {
+                setter.update(|value| *value = !*value)
+            }>"Toggle Small Caps"</button>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+}
@@ -1,7 +1,7 @@
-use leptos::*;
+use leptos::prelude::*;
 
 fn main() {
-    leptos::mount_to_body(|cx| view! { cx, <App/> })
+    leptos::mount::mount_to_body(|| view! { <App/> })
 }
 
 // Often, you want to pass some kind of child view to another
@@ -13,19 +13,19 @@ fn main() {
 //   property
 
 #[component]
-pub fn App(cx: Scope) -> impl IntoView {
-    let (items, _set_items) = create_signal(cx, vec![0, 1, 2]);
+pub fn App() -> impl IntoView {
+    let (items, _set_items) = signal(vec![0, 1, 2]);
     let render_prop = move || {
         // items.with(...) reacts to the value without cloning
         // by applying a function. Here, we pass the `len` method
         // on a `Vec<_>` directly
         let len = move || items.with(Vec::len);
-        view! { cx,
+        view! {
             <p>"Length: " {len}</p>
         }
     };
 
-    view! { cx,
+    view! {
         // This component just displays the two kinds of children,
         // embedding them in some other markup
         <TakesChildren
@@ -50,44 +50,57 @@ pub fn App(cx: Scope) -> impl IntoView {
 /// Displays a `render_prop` and some children within markup.
 #[component]
 pub fn TakesChildren<F, IV>(
-    cx: Scope,
     /// Takes a function (type F) that returns anything that can be
     /// converted into a View (type IV)
     render_prop: F,
-    /// `children` takes the `Children` type this is an alias for `Box<dyn FnOnce(Scope) -> Fragment>`
-    /// If you need a Fn or FnMut, ChildrenFn and ChildrenMut aliases are also provided
+    /// `children` takes the `Children` type this is an alias for `Box<dyn FnOnce() -> AnyView + Send>`
+    /// If you need a Fn or FnMut, ChildrenFn and ChildrenFnMut aliases are also provided
     children: Children,
 ) -> impl IntoView
 where
     F: Fn() -> IV,
     IV: IntoView,
 {
-    view! { cx,
+    view! {
         <h1><code>"<TakesChildren/>"</code></h1>
         <h2>"Render Prop"</h2>
         {render_prop()}
         <hr/>
         <h2>"Children"</h2>
-        {children(cx)}
+        {children()}
     }
 }
 
 /// Wraps each child in an `<li>` and embeds them in a `<ul>`.
 #[component]
-pub fn WrapsChildren(cx: Scope, children: Children) -> impl IntoView {
-    // children(cx) returns a `Fragment`, which has a
-    // `nodes` field that contains a Vec<View>
+pub fn WrapsChildren(children: ChildrenFragment) -> impl IntoView {
+    // `ChildrenFragment` keeps the children apart instead of erasing them into a single `AnyView`:
+    // children() returns a `Fragment`, which has a
+    // `nodes` field that contains a Vec<AnyView>
     // this means we can iterate over the children
     // to create something new!
-    let children = children(cx)
+    let children = children()
         .nodes
         .into_iter()
-        .map(|child| view! { cx, <li>{child}</li> })
-        .collect_view(cx);
+        .map(|child| view! { <li>{child}</li> })
+        .collect_view();
 
     // wrap our wrapped children in a UL
-    view! { cx,
+    view! {
         <h1><code>"<WrapsChildren/>"</code></h1>
         <ul>{children}</ul>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+}
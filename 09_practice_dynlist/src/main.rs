@@ -1,82 +1,261 @@
-use leptos::*;
+use components::{storage, RowHeight, VirtualList};
+use leptos::prelude::*;
+use names::NameGenerator;
+use serde::{Deserialize, Serialize};
+
+mod names;
 
 const INITIAL_LIST_LENGTH: usize = 10;
+/// Matches the `.row` height in `index.html`.
+const ROW_HEIGHT: f64 = 28.0;
 
 fn main() {
-    mount_to_body(|cx| view! { cx, <App/> })
+    mount_to_body(|| view! { <App/> })
 }
 
-fn add_a_name(id: usize) -> String {
-    #[rustfmt::skip]
-    let names: Vec<&str> = vec!["Reyna Watts","Dakota Durham","Tiffany White","Aiden King","Victoria Conner","Phillip Horne","Marlowe Cortez","Zayn Walters","Samara Harrison","Gavin Figueroa","Lilith Payne","Edward Hensley","Malaya Hobbs","Brendan Bradshaw","Berkley Lamb","Kaysen McFarland","Annika Valdez","Kyler Wilkinson","Siena Santiago","Beckham Murphy","Bella Harvey","Cayden Yang","Angelina McKinney","Romeo Velazquez","Jaliyah Mendez","Arthur Nielsen","Vienna Webster","Shawn Short","Cheyenne Rodriguez","Henry Kirby","Skyla Christian","Ledger McDaniel","Dahlia Armstrong","Grant Hendricks","Dani Weeks","Anders Herrera","Ximena Middleton","Misael Levy","Flora Flowers","Saul Klein","Elianna Black","Matteo Olsen","Oaklyn Drake","Jalen Douglas","Aniyah Ryan","Timothy Reyes","Audrey Delarosa","Osiris Wyatt","Liberty Friedman","Darwin Owens","Amaya Fuller","Andre Glenn","Blaire Wolf","Jase Clements","Cara Gregory","Travis Sosa","Cassandra Franklin","Simon Diaz","Elena Casey","Armando Haynes","Lexi McKinney","Romeo Phillips","Naomi Sweeney","Nixon Wiggins","Capri Dennis","Emanuel Kane","Ellianna Ware","Tadeo Cordova","Florence Vo","Gordon Higgins","Leighton Bowman","Francisco David","Haylee Fitzgerald","Peyton Villalobos","Zoya Roy","Marcelo Tanner","Harmoni Glass","Allan O’Neal","Treasure McCann","Heath Yu","Navy Crosby","Tristen Stewart","Maya Serrano","Milan Campos","Sutton Nolan","Maximo Ayers","Simone Matthews","Preston Adkins","Emelia Shaffer","Dexter Butler","Athena Baker","Ezra Murphy","Bella Gonzalez","Ethan Finley","Jovie Blankenship","Ernesto Hull","Andi Knight","Beckett Anderson","Ella Tyler","Emmitt Thompson","Madison Moses","Niklaus Holmes","Bailey Wang","Cohen Rios","Brooke Velasquez","Sullivan Wong","Adelaide Palacios","Thaddeus Garcia","Amelia McCall","Kiaan Conway","Ryann Camacho","Tatum Pham","Raelyn Barber","Solomon Harrington","Legacy Foster","Kayden Robertson","Harmony Duncan","Avery Wagner","Maeve McBride","Denver McCullough","Hana Davila","Grey Fernandez","Amara Shepard","Damari Douglas","Aniyah Briggs","Case Hale","Brinley Pham","Russell Hodge","Coraline Portillo","Wallace Stephens","Millie Hickman","Jakobe Bradford","Rhea Fuentes","Bowen May","Adriana Summers","Darius Camacho","Armani Farrell","Ty Chang","Ophelia Calhoun","Gary Turner","Brooklyn Moore","Levi Brown","Charlotte Welch","Hendrix Frank","Dior Vazquez","Jesse Reeves","Lana Barrett","Angelo Galvan","Dallas Newman","Anderson Avalos","Paloma Watts","Dakota Wheeler","Sydney Salgado","Trace Kennedy","Brianna Medrano","Arian Rios","Brooke Jackson","Sebastian Wiley","Lauryn Church","Terrance Townsend","Azalea Gilmore","Vihaan Mora","Jemma Summers","Darius Bailey","Kennedy Delacruz","Memphis Aguirre","Ariah Velazquez","Drew Manning","Jennifer Guerrero","Bryce Blake","Amanda McCarthy","Devin Walters","Samara Zamora","Quentin Fernandez","Amara Vu","Kamdyn Pugh","Landry Espinosa","Khalid Pollard","Marisol Potter","Lucca Carr","Rowan Phan","Maison Phan","Elsa Pitts","Trey McCarthy","Kira Hawkins","Victor Bowers","Elisa Swanson","Hugo Houston","Lylah Tate","Dalton Lang","Amirah Hess","Lawrence Contreras","Daniela Person","Moses Clark","Chloe Wilcox","Jerry Park","Lia Atkins","Cason Roach","Lyanna Sanders","Jose Underwood","Ensley Lester","Lee Vu","Kimora Rios","Israel Booth","Zariyah Clements","Fisher McMahon","Belen Conrad","Dilan Cardenas","Raven McCarty","Blaise Khan","Mabel Cole","Nathaniel Lawrence","Lauren Kent","Mekhi Fuentes","Madeleine Osborne","Augustus Compton","Elina Henry","Carlos Adams","Stella Patterson","Amir Johnson","Emma Roth","Roy Bond","Alena Cuevas","Brecken Phelps","Laney Cross","Fabian Mullen","Shay Ramirez","David Santana","Myra Hartman","Baker Patel","Madeline Wolfe","Donovan Lindsey","Colette Kramer","Kylan Smith","Olivia Lane","Matias Phan","Elsa David","Alonso Stanton","Jaycee Spence","Cillian Ayers","Simone Ward","Jameson Holloway","Mae Burch","Gerald Lee","Scarlett Joseph","Kyle Chan","Hattie Rice","Graham Weaver","Teagan Fields","Clayton Reese","Rosemary Duarte","Abdullah Golden","Giuliana Gomez","Isaiah Smith","Olivia Byrd","Cristian McClain","Marleigh Schwartz","Edwin Glenn","Blaire Phillips","Andrew Xiong","Amayah Garza","Judah Rice","Ada Bernal","Eithan Joseph","Gracelynn Shaw","Elliot Proctor","Chandler Duncan","Avery Middleton","Madalyn Lambert","Mario Rose","Magnolia Kline","Ramon Beard","Ezra Moss","Porter Odom","Laylani Evans","Elias Flores","Emilia Harrison","Gavin Harper","Ana Collins","Miles Holmes","Bailey Moran","Tate Hansen","Hope Day","Kayson Ochoa","Luciana May","Finley Yu","Navy Cummings","Raiden Neal","Talia Solis","Ronin Love","Avianna Chapman","Knox Green","Zoe Walsh","Bodhi Gould","Violeta Howard","Jeremiah Arias","Aleah Skinner","Ridge Pitts","Nala Carter","Maverick Lin","Makenna Ballard","Kenzo McCarty","Halo Dougherty","Brett Johnson","Emma House","Yehuda Calderon","Serena Hubbard","Forrest Rodriguez","Evelyn Page","Pablo Walsh","Leia Mason","Brandon Travis","Mazikee Foster","Kayden Richard","Davina Archer","Ephraim Zuniga","Leslie Ingram","Tripp Richmond","Whitney Byrd","Cristian Thomas","Elizabeth Rollins","Wes Lee","Scarlett Calhoun","Gary Smith","Olivia Marin","Aldo McMahon","Belen Patton","Moises Armstrong","Presley Rivas","Damon Esparza","Ramona Thomas","Logan Hood","Briana Hines","Uriel Lopez","Gianna Boyle","Robin Guevara","Teresa Briggs","Case Estrada","Sawyer Diaz","Nathan Henderson","Maria Rich","Miller Hicks","Alina Everett","Camilo Lane","Amy Good","Davian Gardner","Jordyn Wong","Walter Sanford","Emerald Walls","Larry Hutchinson","Jamie Steele","Elian Conway","Ryann Collins","Miles Calhoun","Thalia Walls","Larry Gray","Sarah Mann","Nehemiah Shaffer","Alanna Bush","Tyson Gilmore","Chanel Bean","Mccoy Simon","Kalani Beard","Nathanael Munoz","Kehlani Carr","Kash Coffey","Paola Wood","Carson Morse","Kairi Lu","Duncan Jimenez","Adeline Patton","Moises Hanson","Mariana Welch","Hendrix Le","Myla Hunter","Archer Romero","Eliza Hurley","Van Bernal","Emmeline Montes","Darren Adams","Stella Lester","Lee Franklin","Angela Reed","Easton Hill","Hannah Franklin","Simon Maddox","Zainab Clayton","Boston Gillespie","Alianna Carey","Watson Cross","Nayeli Bradshaw","Emory Dunlap","Iliana Roth","Roy Fox","Juliette Calhoun","Gary Orr","Alaiya Snyder","Thiago Jarvis","Elisabeth Mitchell","Jaxon McCann","Joyce Proctor","Vance Dorsey","Addyson Miller","Benjamin Harrell","Kara Gutierrez","Luca Bowman","Fiona Galindo","Salvatore Bass","Zahra Guevara","Tommy Richard","Davina Valentine","Demetrius Cantrell","Yamileth Mendez","Arthur Jacobs","Camilla Love","Jeffrey Parrish","Tiana Shepard","Damari Lam","Karina Yates","Braylon McCann","Joyce Vaughn","Remy Drake","Jayleen Mayo","Jericho Fry","Clarissa Booker","Dominik Shannon","Harlee Weaver","Tucker Skinner","Mara Franco","Gage Villalobos","Zoya Huffman","Chris Thornton","Haisley Kline","Ramon Davis","Mia Villegas","Clyde Yoder","Emerie O’brien","Riley Horn","Avah Williamson","Emerson Guerra","Edith Bond","Roger Case","Cleo Medina","George Booker","Nataly Crosby","Tristen Simpson","Anastasia Mays","Jadiel Newton","Braelynn Humphrey","Krew Quinn","Heaven Keller","Nico Wheeler","Sydney McCormick","Jasiah Webb","Ariella Hurst","Neil Bradshaw","Berkley Martin","Mateo Conrad","Bexley Cortes","Banks Bauer","Haley Felix","Rodney Le","Myla Tyler","Emmitt Preston","Indie Burch","Gerald Larson","Alayna Randolph","Eugene Sanford","Emerald Odom","Kylian Armstrong","Presley McCormick","Jasiah Galvan","Dallas King","Julian Hendricks","Dani Odom","Kylian Rodgers","Selah Knox","Valentin Gilmore","Chanel Cain","Benson Zamora","Sierra Church","Terrance Rogers","Madelyn McCullough","Briar Holmes","Bailey Ochoa","Winston Swanson","Helen Robbins","Finnegan Camacho","Armani Hoffman","Steven Bullock","Winnie McPherson","Foster Lynch","Malia Rivera","Charles Hall","Leah Tran","Braxton Richard","Davina Hughes","Everett Figueroa","Lilith Bullock","Ben Cruz","Claire Perez","Owen Sullivan","Melanie Cherry","Rome Holmes","Bailey Walker","Luke Garrett","Tessa Cox","Connor Burton","Miriam Robinson","Matthew Arellano","Faye Watkins","Nash Stevens","Katherine Rodriguez","Henry McDonald","Daisy Ashley","Kylen Case","Cleo Simon","Zayne Pratt","Ailani Hodge","Reign Sloan","Selene Hernandez","Mason Bishop","Brooklynn Henson","Bellamy Ball","Abby Garza","Judah Branch","Luisa Pruitt","Gatlin Byrd","Giselle Doyle","Kashton Huff","Karsyn Espinoza","Dallas Brady","Ryan Crosby","Tristen Strickland","Nia Grant","Leon Beard","Ezra Cisneros","Alden Johns","Giovanna Jensen","Cash Golden","Giuliana Frank","Braylen Finley","Jovie Santiago","Beckham Gibbs","Carter McCoy","Jett Khan","Mabel Blackwell","Marcellus Burton","Miriam Newman","Anderson Mata","Lillie Marquez","Malakai Manning","Jennifer Jaramillo","Riggs Carey","Alora McCormick","Jasiah Woods","Reese Parrish","Karsyn Craig","Brynn Mills","Alex Fischer","Maci Wagner","Enzo Rice","Ada Wall","Issac Howell","Mckenna Dunlap","Aries Andersen","Zoie Murphy","Cameron Rocha","Emmie Chandler","Royal Gilmore","Chanel Nguyen","Gabriel Huerta","Dulce Craig","Odin Melton","Kamiyah Sanchez","Joseph Andrade","Emmy Gilbert","Tobias Serrano","Allie Hardy","Jayceon Roman","Astrid Santana","Mohamed Byrd","Giselle Gilbert","Tobias Strickland","Nia Hoffman","Steven Blackburn","Frida Chambers","Orion Shah","Angelica Grant","Leon Robbins","Stevie Rhodes","Titus Hodge","Coraline Ray","Arlo Zhang","Sarai Kemp","Melvin Munoz","Kehlani Cano","Terry Carlson","Kali Bullock","Ben Hensley","Malaya Murphy","Cameron Phan","Elsa Reilly","Alvaro Kirby","Skyla Baxter","Tomas Valenzuela","Henley Bartlett","Kace Andrews","Payton Soto","Barrett Carpenter","Lilly Castaneda","Collin Chavez","Nevaeh Arias","Alec Schmidt","Kimberly Long","Jace McLaughlin","Stephanie Huff","Finnley Alvarez","Leilani Valentine","Demetrius Le","Myla Reid","Josue Walters","Samara Simpson","Elliott Sanders","Everleigh Tapia","Samir Macdonald","Rosalia Cain","Benson Blankenship","Rosalee May","Finley Walter","Penny Small","Rudy Person","Dylan Rich","Miller Fuentes","Madeleine Young","Asher Villa","Johanna Hancock","Rex Krueger","Kamari Quinn","Rhys Portillo","Nathalie York","Leandro Holloway","Mae Miles","Jared Cruz","Claire Wolfe","Donovan Riley","Kayla Esparza","Carl Escobar","Erin Rivera","Charles Roman","Astrid Trejo","Wesson Robbins","Stevie Brooks","Jordan Krueger","Kamari Bell","Emmett Graham","Alaia Saunders","Kasen Bennett","Josephine Black","Matteo Huff","Karsyn Friedman","Darwin Lowery","Estelle Richmond","Mordechai Olson","Isabel Coleman","Micah Santos","Alana Le","Damien Whitaker","Ivanna Conner","Phillip Dickerson","Opal Aguirre","Andy Chan","Hattie Singleton","Landyn Gould","Violeta Kline","Ramon Kerr","Baylee Lowery","Jaxxon Greer","Reina Wu","Kyson Cohen","Destiny Moyer","Ahmir Peterson","Caroline Malone","Ruben Erickson","Sabrina Flynn","Kannon Bean","Jenesis Wilkerson","Carmelo Peralta","Malayah Barron","Dustin Owens","Amaya Lynch","Zane Higgins","Leighton Schroeder","Izaiah Garza","River Tapia","Samir Norris","Arielle Noble","Idris Barnes","Liliana Taylor","Jackson Bean","Jenesis Bryan","Jaxtyn Galvan","Dallas Ward","Jameson Potter","Rory Roman","Kian Richard","Davina Esquivel","Bridger Walsh","Leia Hutchinson","Korbin O’Donnell","Bellamy Collier","Edison Matthews","Lila Avalos","Coen Gilmore","Chanel Rivers","Bear Cook","Aaliyah Oliver","Karson Acosta","Kaia Barber","Solomon Donovan","Azariah Kline","Ramon Patel","Madeline Trevino","Jaime Esparza","Ramona Tanner","Bruno Schaefer","Mavis Chavez","Ian Escobar","Erin Cantrell","Harris Salas","Amber Walker","Luke Parks","Ainsley Sandoval","Brantley Melendez","Bethany Valencia","Dax Wells","Cecilia Pitts","Trey Mullins","Maliyah Blanchard","Adler Eaton","Miley Yoder","Johan Doyle","Annalise Gilmore","Vihaan Ruiz","Emery Alfaro","Xzavier Lozano","Cecelia Thornton","Malik Hahn","Fallon Mata","Ray Buchanan","Maryam Parker","Caleb Miles","Alessandra Barajas","Brennan Lowe","Amari Gallagher","Marcos Whitaker","Ivanna Kerr","Louie Best","Lexie Reyes","Eli Pugh","Landry Estes","Hakeem Cortes","Lea Savage","Keaton Hickman","Scarlette Jarvis","Marlon Armstrong","Presley Hebert","Guillermo Cantrell","Yamileth Dalton","Fletcher Lester","Averi Brock","Julio Black","Molly Pineda","Gerardo Marsh","Adelina Hutchinson","Korbin Pratt","Ailani Larson","Rafael Brewer","Thea Thomas","Logan Prince","Greta Stevenson","Callan Rocha","Emmie Miles","Jared Fletcher","Anaya Richmond","Mordechai Hickman","Scarlette Santana","Mohamed Patton","Lorelei Spence","Cillian Baldwin","Esmeralda Hensley","Layne Baldwin","Esmeralda Ferguson","Miguel Parks","Ainsley Walsh","Bodhi Benitez","Aliza Maldonado","Javier Novak","Kaiya Flowers","Saul Clay","Aliana Powell","Bennett Page","Cataleya Norton","Callen Cruz","Claire Cardenas","Johnathan Romero","Eliza Watson","Greyson Maynard","Carolyn Lim","Cal Vo","Artemis Roy","Marcelo Barton","Danna Cobb","Raphael Walton","Scarlet Ortega","Kobe Marquez","Milani Buckley","Aryan Garrison","Cadence Bernal","Eithan Elliott","Noelle Leblanc","Braden Velasquez","Esme Travis","Willie Fuller","Oakley Harrell","Nelson Murray","Faith Webster","Shawn Castaneda","Keira Doyle","Kashton Gordon","Taylor Aguirre","Andy Marshall","Adalyn Chambers","Orion Mendoza","Cora Blackwell","Marcellus Wells","Cecilia Vang","Jimmy Brooks","Autumn Moyer","Ahmir McIntosh","Gwen Morrow","Kyree Coffey","Paola Christian","Ledger Stanton","Jaycee Larson","Rafael Hancock","Katelyn Walker","Luke Wood","Natalia Perkins","Kyrie Matthews","Lila Hogan","Sonny Gillespie","Alianna Franklin","Simon Houston","Lylah Dalton","Fletcher Lopez","Gianna Miles","Jared Mayer","Ainhoa Morales","Aaron Hogan","Kathryn Thomas","Logan Wood","Natalia McKee","Bjorn Phan","Elsa Townsend","Alexis Roberts","Paisley Ballard","Kenzo Anderson","Ella Booker","Dominik Ponce","Aileen Zhang","Isaias Jackson","Avery Person","Moses Johnson","Emma Sosa","Emir Hardy","Jessica Hahn","Kabir Boyer","Chaya Bravo","Genesis Vaughn","Reign Hampton","Hank Medrano","Halle Weiss","Koa Barrera","Beatrice Holmes","King Boone","Mariam Quintero","Thatcher Aguilar","Josie Glass","Allan Cooper","Serenity Matthews","Preston Randall","Christina Rojas","Colin Montes","Roselyn Quintana","Kelvin Griffin","Charlie Higgins","Sterling Friedman","Aspyn Hughes","Everett Schroeder","Cameron Durham","Kellen Vance","Maxine Dean","Ronan Macdonald","Rosalia Gentry","Magnus Rios","Brooke Zhang","Isaias Schultz","Briella Norman","Aziel Yu","Navy Ellison","Kye Young","Zoey Bridges","Mohammed Ashley","Khalani Fleming","Fernando Chan","Hattie Vu","Kamdyn Rodriguez","Evelyn Robinson","Matthew Bartlett","Aubrielle Galvan","Kingsley Reilly","Tori Rush","Kaiser Sheppard","Veda Vincent","Aarav Walton","Scarlet McCall","Kiaan Serrano","Allie Garrett","Kairo Gallagher","Elliott Curtis","Muhammad Page","Cataleya McGee","Conner Hutchinson","Jamie Salas","Zaiden Moyer","Zola Prince","Aron Marks","Monica Mays","Jadiel Ahmed","Jolie Hampton","Hank Davidson","Jayla Pacheco","Erik Stephenson","Khaleesi Travis","Willie Duarte","Kynlee Hendrix","Korbyn Friedman","Aspyn Moody","Ryland Graham","Alaia Perkins","Kyrie Nielsen","Vienna Hernandez","Mason Winters","Kataleya Wang","Cohen McBride","Kelsey Pena","Marcus Chavez","Nevaeh Duran","Ismael Lyons","Kenzie Richard","Ahmed Bravo","Amoura Webster","Shawn Noble","Hunter Potts","Alfred Woods","Reese Pena","Marcus Zamora","Sierra Fitzgerald","Peyton Dodson","Etta Dennis","Emanuel Fitzpatrick","Annabella McLaughlin","Ibrahim Good","Nathalia Suarez","Soren Fuller","Oakley Gilmore","Vihaan Brewer","Thea Beck","Eduardo Barker","Remington McConnell","London Kennedy","Brianna Ibarra","Asa Pacheco","Paris Charles","Conrad Bruce","Marilyn Gallegos","Jonas Hendricks","Dani Barr","Harley McGee","Kayleigh Hoffman","Steven Pennington","Yareli Villegas","Clyde Hensley","Malaya Clarke","Stetson Owens","Amaya Patton","Moises Guevara","Teresa Browning","Rohan Gilbert",];
+/// The seed is fixed, so a fresh list always starts with the same names.
+const NAMES_SEED: u64 = 2024;
 
-    names[id].to_owned()
+fn add_a_name(id: usize) -> String {
+    NameGenerator::new(NAMES_SEED).name(id)
 }
 
 #[component]
-fn App(cx: Scope) -> impl IntoView {
-    view! { cx,
+fn App() -> impl IntoView {
+    view! {
         <h1>"Demo"</h1>
         <MakeList/>
     }
 }
 
-#[component]
-#[rustfmt::skip]
-fn MakeList(cx: Scope) -> impl IntoView {
-    let mut next_count_id = INITIAL_LIST_LENGTH;
-    let list_elements: Vec<_> = (0..next_count_id)
-        .map(|id| {
-            (
+/// One row of the list, this is what ends up in local storage.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Person {
+    id: usize,
+    count: usize,
+    name: String,
+}
+
+/// The whole list as it is stored. `next_id` is kept too, so ids stay unique after a reload.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct SavedList {
+    next_id: usize,
+    people: Vec<Person>,
+}
+
+impl SavedList {
+    fn initial() -> Self {
+        let people = (0..INITIAL_LIST_LENGTH)
+            .map(|id| Person {
                 id,
-                (create_signal(cx, id + 1), create_signal(cx, add_a_name(id))),
-            )
-        })
+                count: id + 1,
+                name: add_a_name(id),
+            })
+            .collect();
+
+        Self {
+            next_id: INITIAL_LIST_LENGTH,
+            people,
+        }
+    }
+}
+
+const STORAGE_KEY: &str = "practice_dynlist";
+
+/// A list we can't read back (an older format, a hand edited value) just starts a fresh one.
+fn load_list() -> SavedList {
+    storage::load(STORAGE_KEY).unwrap_or_else(SavedList::initial)
+}
+
+fn save_list(list: &SavedList) {
+    storage::save(STORAGE_KEY, list);
+}
+
+/// The order the list is shown in. Sorting never touches the list itself, only what is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum SortBy {
+    #[default]
+    Added,
+    Name,
+    Count,
+}
+
+impl SortBy {
+    fn from_value(value: &str) -> Self {
+        match value {
+            "name" => SortBy::Name,
+            "count" => SortBy::Count,
+            _ => SortBy::Added,
+        }
+    }
+}
+
+/// Positions in `people` of the rows to show, in the order to show them.
+/// `filter` matches any part of the name, ignoring case.
+fn arrange(people: &[Person], sort_by: SortBy, filter: &str) -> Vec<usize> {
+    let filter = filter.trim().to_lowercase();
+    let mut shown: Vec<usize> = (0..people.len())
+        .filter(|&i| people[i].name.to_lowercase().contains(&filter))
         .collect();
 
-    let (wrap_list_count, set_wrap_list_count) = create_signal(cx, list_elements);
+    // both sorts are stable, so rows that compare equal stay in the order they were added
+    match sort_by {
+        SortBy::Added => {}
+        SortBy::Name => shown.sort_by(|&a, &b| people[a].name.cmp(&people[b].name)),
+        SortBy::Count => shown.sort_by(|&a, &b| people[b].count.cmp(&people[a].count)),
+    }
+    shown
+}
+
+#[component]
+#[rustfmt::skip]
+fn MakeList() -> impl IntoView {
+    let saved = load_list();
+    let next_id = StoredValue::new(saved.next_id);
+    // every row gets its own signal, so `+1` only touches that row
+    let list = RwSignal::new(saved.people.into_iter().map(RwSignal::new).collect::<Vec<_>>());
+
+    // reading every row here makes the effect run again whenever any of them changes
+    Effect::new(move |_| {
+        let people = list.with(|list| list.iter().map(|person| person.get()).collect());
+        save_list(&SavedList { next_id: next_id.get_value(), people });
+    });
+
+    let (sort_by, set_sort_by) = signal(SortBy::Added);
+    let (filter, set_filter) = signal(String::new());
+
+    // a derived signal: it reads the rows, the sort order and the filter, and runs again whenever
+    // any of them changes. The rows keep their keys, so <For/> only moves them around
+    let shown = move || {
+        list.with(|list| {
+            let people: Vec<Person> = list.iter().map(|person| person.get()).collect();
+            arrange(&people, sort_by.get(), &filter.read())
+                .into_iter()
+                .map(|i| list[i])
+                .collect::<Vec<_>>()
+        })
+    };
 
     let push_names = move |_| {
         (0..20).for_each(|_| {
-            let sig = (
-                create_signal(cx, next_count_id + 1),
-                create_signal(cx, add_a_name(next_count_id)),
-            );
+            let id = next_id.get_value();
+            let person = RwSignal::new(Person { id, count: id + 1, name: add_a_name(id) });
 
-            set_wrap_list_count.update(move |list_count| list_count.push((next_count_id, sig)));
-            next_count_id += 1;
+            list.update(move |list| list.push(person));
+            next_id.set_value(id + 1);
         });
     };
 
-    view! { cx,
+    view! {
         <h3>"Dynamic list practice"</h3>
         <div>
             <button on:click=push_names>"Add 20 names"</button>
-            <ul>
-                <For
-                    each=wrap_list_count
-                    key=|counter| counter.0
-                    view=move |cx, (id, ((n, set_n), (name, set_name)))| {
-                        view! { cx,
-                            <li>
-                                <button on:click=move |_| {
-                                    set_n
-                                        .update(|num| {
-                                            set_name(add_a_name(*num));
-                                            *num += 1;
-                                        });
-                                }>"+1"</button>
-                                <button on:click=move |_| set_wrap_list_count.update(|elem| elem.retain(|(count_id, _)| count_id != &id))>
-                                    "Remove"
-                                </button>
-                                {n}
-                                "-"
-                                {name}
-                            </li>
-                        }
+            <select on:change=move |ev| set_sort_by.set(SortBy::from_value(&event_target_value(&ev)))>
+                <option value="added">"Order added"</option>
+                <option value="name">"Name"</option>
+                <option value="count">"Count (highest first)"</option>
+            </select>
+            <input
+                type="search"
+                placeholder="Filter names"
+                prop:value=filter
+                on:input=move |ev| set_filter.set(event_target_value(&ev))
+            />
+            // only the rows in view are on the page, so the list can grow as long as you like
+            <VirtualList
+                each=shown
+                key=|person| person.with_untracked(|person| person.id)
+                view=move |person| {
+                    let id = person.with_untracked(|person| person.id);
+                    view! {
+                        <div class="row">
+                            <button on:click=move |_| {
+                                person
+                                    .update(|person| {
+                                        person.name = add_a_name(person.count);
+                                        person.count += 1;
+                                    });
+                            }>"+1"</button>
+                            <button on:click=move |_| list.update(|list| list.retain(|person| person.with_untracked(|person| person.id) != id))>
+                                "Remove"
+                            </button>
+                            {move || person.with(|person| person.count)}
+                            "-"
+                            {move || person.with(|person| person.name.clone())}
+                        </div>
                     }
-                />
-            </ul>
+                }
+                row_height=RowHeight::Fixed(ROW_HEIGHT)
+            />
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+
+    #[test]
+    fn saved_list_round_trips() {
+        let mut list = SavedList::initial();
+        list.people.remove(3);
+        list.people[0].count = 7;
+        list.next_id = 30;
+
+        let json = serde_json::to_string(&list).unwrap();
+
+        assert_eq!(storage::parse::<SavedList>(&json), Some(list));
+    }
+
+    fn person(id: usize, count: usize, name: &str) -> Person {
+        Person {
+            id,
+            count,
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn arrange_sorts() {
+        let people = [
+            person(0, 3, "Cleo Vu"),
+            person(1, 9, "Ada Wall"),
+            person(2, 3, "Bo Lin"),
+        ];
+
+        assert_eq!(arrange(&people, SortBy::Added, ""), [0, 1, 2]);
+        assert_eq!(arrange(&people, SortBy::Name, ""), [1, 2, 0]);
+        // equal counts keep the order they were added in
+        assert_eq!(arrange(&people, SortBy::Count, ""), [1, 0, 2]);
+    }
+
+    #[test]
+    fn arrange_filters_then_sorts() {
+        let people = [
+            person(0, 3, "Cleo Vu"),
+            person(1, 9, "Ada Wall"),
+            person(2, 3, "Bo Lin"),
+        ];
+
+        assert_eq!(arrange(&people, SortBy::Name, " l "), [1, 2, 0]);
+        assert_eq!(arrange(&people, SortBy::Name, "LIN"), [2]);
+        assert_eq!(arrange(&people, SortBy::Count, "nobody"), [] as [usize; 0]);
+    }
+
+    #[test]
+    fn unreadable_storage_is_ignored() {
+        assert_eq!(storage::parse::<SavedList>("not json"), None);
+        assert_eq!(
+            storage::parse::<SavedList>(r#"[[0, [1, "Reyna Watts"]]]"#),
+            None
+        );
+    }
+}
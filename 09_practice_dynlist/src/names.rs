@@ -0,0 +1,101 @@
+//! Names for the rows of the list.
+//!
+//! Every first name is combined with every surname, so there are far more names than rows anyone will add. The
+//! order of the combinations depends on a seed, the same seed always hands out the same names.
+
+#[rustfmt::skip]
+const FIRST_NAMES: &[&str] = &["Reyna","Dakota","Tiffany","Aiden","Victoria","Phillip","Marlowe","Zayn","Samara","Gavin","Lilith","Edward","Malaya","Brendan","Berkley","Kaysen","Annika","Kyler","Siena","Beckham","Bella","Cayden","Angelina","Romeo","Jaliyah","Arthur","Vienna","Shawn","Cheyenne","Henry","Skyla","Ledger","Dahlia","Grant","Dani","Anders","Ximena","Misael","Flora","Saul","Elianna","Matteo","Oaklyn","Jalen","Aniyah","Timothy","Audrey","Osiris","Liberty","Darwin","Amaya","Andre","Blaire","Jase","Cara","Travis","Cassandra","Simon","Elena","Armando","Lexi","Naomi","Nixon","Capri","Emanuel","Ellianna","Tadeo","Florence","Gordon","Leighton","Francisco","Haylee","Peyton","Zoya","Marcelo","Harmoni","Allan","Treasure","Heath","Navy","Tristen","Maya","Milan","Sutton","Maximo","Simone","Preston","Emelia","Dexter","Athena","Ezra","Ethan","Jovie","Ernesto","Andi","Beckett","Ella","Emmitt","Madison","Niklaus","Bailey","Cohen","Brooke","Sullivan","Adelaide","Thaddeus","Amelia","Kiaan","Ryann","Tatum","Raelyn","Solomon","Legacy","Kayden","Harmony","Avery","Maeve","Denver","Hana","Grey","Amara","Damari","Case","Brinley","Russell","Coraline","Wallace","Millie","Jakobe","Rhea","Bowen","Adriana","Darius","Armani","Ty","Ophelia","Gary","Brooklyn","Levi","Charlotte","Hendrix","Dior","Jesse","Lana","Angelo","Dallas","Anderson","Paloma","Sydney","Trace","Brianna","Arian","Sebastian","Lauryn","Terrance","Azalea","Vihaan","Jemma","Kennedy","Memphis","Ariah","Drew","Jennifer","Bryce","Amanda","Devin","Quentin","Kamdyn","Landry","Khalid","Marisol","Lucca","Rowan","Maison","Elsa","Trey","Kira","Victor","Elisa","Hugo","Lylah","Dalton","Amirah","Lawrence","Daniela","Moses","Chloe","Jerry","Lia","Cason","Lyanna","Jose","Ensley","Lee","Kimora","Israel","Zariyah","Fisher","Belen","Dilan","Raven","Blaise","Mabel","Nathaniel","Lauren","Mekhi","Madeleine","Augustus","Elina","Carlos","Stella","Amir","Emma","Roy","Alena","Brecken","Laney","Fabian","Shay","David","Myra","Baker","Madeline","Donovan","Colette","Kylan","Olivia","Matias","Alonso","Jaycee","Cillian","Jameson","Mae","Gerald","Scarlett","Kyle","Hattie","Graham","Teagan","Clayton","Rosemary","Abdullah","Giuliana","Isaiah","Cristian","Marleigh","Edwin","Andrew","Amayah","Judah","Ada","Eithan","Gracelynn","Elliot","Chandler","Madalyn","Mario","Magnolia","Ramon","Porter","Laylani","Elias","Emilia","Ana","Miles","Tate","Hope","Kayson","Luciana","Finley","Raiden","Talia","Ronin","Avianna","Knox","Zoe","Bodhi","Violeta","Jeremiah","Aleah","Ridge","Nala","Maverick","Makenna","Kenzo","Halo","Brett","Yehuda","Serena","Forrest","Evelyn","Pablo","Leia","Brandon","Mazikee","Davina","Ephraim","Leslie","Tripp","Whitney","Elizabeth","Wes","Aldo","Moises","Presley","Damon","Ramona","Logan","Briana","Uriel","Gianna","Robin","Teresa","Sawyer","Nathan","Maria","Miller","Alina","Camilo","Amy","Davian","Jordyn","Walter","Emerald","Larry","Jamie","Elian","Thalia","Sarah","Nehemiah","Alanna","Tyson","Chanel","Mccoy","Kalani","Nathanael","Kehlani","Kash","Paola","Carson","Kairi","Duncan","Adeline","Mariana","Myla","Archer","Eliza","Van","Emmeline","Darren","Angela","Easton","Hannah","Zainab","Boston","Alianna","Watson","Nayeli","Emory","Iliana","Juliette","Alaiya","Thiago","Elisabeth","Jaxon","Joyce","Vance","Addyson","Benjamin","Kara","Luca","Fiona","Salvatore","Zahra","Tommy","Demetrius","Yamileth","Camilla","Jeffrey","Tiana","Karina","Braylon","Remy","Jayleen","Jericho","Clarissa","Dominik","Harlee","Tucker","Mara","Gage","Chris","Haisley","Mia","Clyde","Emerie","Riley","Avah","Emerson","Edith","Roger","Cleo","George","Nataly","Anastasia","Jadiel","Braelynn","Krew","Heaven","Nico","Jasiah","Ariella","Neil","Mateo","Bexley","Banks","Haley","Rodney","Indie","Alayna","Eugene","Kylian","Julian","Selah","Valentin","Benson","Sierra","Madelyn","Briar","Winston","Helen","Finnegan","Steven","Winnie","Foster","Malia","Charles","Leah","Braxton","Everett","Ben","Claire","Owen","Melanie","Rome","Luke","Tessa","Connor","Miriam","Matthew","Faye","Nash","Katherine","Daisy","Kylen","Zayne","Ailani","Reign","Selene","Mason","Brooklynn","Bellamy","Abby","Luisa","Gatlin","Giselle","Kashton","Karsyn","Ryan","Nia","Leon","Alden","Giovanna","Cash","Braylen","Carter","Jett","Marcellus","Lillie","Malakai","Riggs","Alora","Reese","Brynn","Alex","Maci","Enzo","Issac","Mckenna","Aries","Zoie","Cameron","Emmie","Royal","Gabriel","Dulce","Odin","Kamiyah","Joseph","Emmy","Tobias","Allie","Jayceon","Astrid","Mohamed","Frida","Orion","Angelica","Stevie","Titus","Arlo","Sarai","Melvin","Terry","Kali","Alvaro","Tomas","Henley","Kace","Payton","Barrett","Lilly","Collin","Nevaeh","Alec","Kimberly","Jace","Stephanie","Finnley","Leilani","Josue","Elliott","Everleigh","Samir","Rosalia","Rosalee","Penny","Rudy","Dylan","Asher","Johanna","Rex","Kamari","Rhys","Nathalie","Leandro","Jared","Kayla","Carl","Erin","Wesson","Jordan","Emmett","Alaia","Kasen","Josephine","Estelle","Mordechai","Isabel","Micah","Alana","Damien","Ivanna","Opal","Andy","Landyn","Baylee","Jaxxon","Reina","Kyson","Destiny","Ahmir","Caroline","Ruben","Sabrina","Kannon","Jenesis","Carmelo","Malayah","Dustin","Zane","Izaiah","River","Arielle","Idris","Liliana","Jackson","Jaxtyn","Rory","Kian","Bridger","Korbin","Edison","Lila","Coen","Bear","Aaliyah","Karson","Kaia","Azariah","Jaime","Bruno","Mavis","Ian","Harris","Amber","Ainsley","Brantley","Bethany","Dax","Cecilia","Maliyah","Adler","Miley","Johan","Annalise","Emery","Xzavier","Cecelia","Malik","Fallon","Ray","Maryam","Caleb","Alessandra","Brennan","Amari","Marcos","Louie","Lexie","Eli","Hakeem","Lea","Keaton","Scarlette","Marlon","Guillermo","Fletcher","Averi","Julio","Molly","Gerardo","Adelina","Rafael","Thea","Greta","Callan","Anaya","Lorelei","Esmeralda","Layne","Miguel","Aliza","Javier","Kaiya","Aliana","Bennett","Cataleya","Callen","Johnathan","Greyson","Carolyn","Cal","Artemis","Danna","Raphael","Scarlet","Kobe","Milani","Aryan","Cadence","Noelle","Braden","Esme","Willie","Oakley","Nelson","Faith","Keira","Taylor","Adalyn","Cora","Jimmy","Autumn","Gwen","Kyree","Katelyn","Natalia","Kyrie","Sonny","Ainhoa","Aaron","Kathryn","Bjorn","Alexis","Paisley","Aileen","Isaias","Emir","Jessica","Kabir","Chaya","Genesis","Hank","Halle","Koa","Beatrice","King","Mariam","Thatcher","Josie","Serenity","Christina","Colin","Roselyn","Kelvin","Charlie","Sterling","Aspyn","Kellen","Maxine","Ronan","Magnus","Briella","Aziel","Kye","Zoey","Mohammed","Khalani","Fernando","Aubrielle","Kingsley","Tori","Kaiser","Veda","Aarav","Kairo","Muhammad","Conner","Zaiden","Zola","Aron","Monica","Jolie","Jayla","Erik","Khaleesi","Kynlee","Korbyn","Ryland","Kataleya","Kelsey","Marcus","Ismael","Kenzie","Ahmed","Amoura","Hunter","Alfred","Etta","Annabella","Ibrahim","Nathalia","Soren","Eduardo","Remington","London","Asa","Paris","Conrad","Marilyn","Jonas","Harley","Kayleigh","Yareli","Stetson","Rohan",];
+
+#[rustfmt::skip]
+const SURNAMES: &[&str] = &["Watts","Durham","White","King","Conner","Horne","Cortez","Walters","Harrison","Figueroa","Payne","Hensley","Hobbs","Bradshaw","Lamb","McFarland","Valdez","Wilkinson","Santiago","Murphy","Harvey","Yang","McKinney","Velazquez","Mendez","Nielsen","Webster","Short","Rodriguez","Kirby","Christian","McDaniel","Armstrong","Hendricks","Weeks","Herrera","Middleton","Levy","Flowers","Klein","Black","Olsen","Drake","Douglas","Ryan","Reyes","Delarosa","Wyatt","Friedman","Owens","Fuller","Glenn","Wolf","Clements","Gregory","Sosa","Franklin","Diaz","Casey","Haynes","Phillips","Sweeney","Wiggins","Dennis","Kane","Ware","Cordova","Vo","Higgins","Bowman","David","Fitzgerald","Villalobos","Roy","Tanner","Glass","O’Neal","McCann","Yu","Crosby","Stewart","Serrano","Campos","Nolan","Ayers","Matthews","Adkins","Shaffer","Butler","Baker","Gonzalez","Finley","Blankenship","Hull","Knight","Anderson","Tyler","Thompson","Moses","Holmes","Wang","Rios","Velasquez","Wong","Palacios","Garcia","McCall","Conway","Camacho","Pham","Barber","Harrington","Foster","Robertson","Duncan","Wagner","McBride","McCullough","Davila","Fernandez","Shepard","Briggs","Hale","Hodge","Portillo","Stephens","Hickman","Bradford","Fuentes","May","Summers","Farrell","Chang","Calhoun","Turner","Moore","Brown","Welch","Frank","Vazquez","Reeves","Barrett","Galvan","Newman","Avalos","Wheeler","Salgado","Kennedy","Medrano","Jackson","Wiley","Church","Townsend","Gilmore","Mora","Bailey","Delacruz","Aguirre","Manning","Guerrero","Blake","McCarthy","Zamora","Vu","Pugh","Espinosa","Pollard","Potter","Carr","Phan","Pitts","Hawkins","Bowers","Swanson","Houston","Tate","Lang","Hess","Contreras","Person","Clark","Wilcox","Park","Atkins","Roach","Sanders","Underwood","Lester","Booth","McMahon","Conrad","Cardenas","McCarty","Khan","Cole","Lawrence","Kent","Osborne","Compton","Henry","Adams","Patterson","Johnson","Roth","Bond","Cuevas","Phelps","Cross","Mullen","Ramirez","Santana","Hartman","Patel","Wolfe","Lindsey","Kramer","Smith","Lane","Stanton","Spence","Ward","Holloway","Burch","Lee","Joseph","Chan","Rice","Weaver","Fields","Reese","Duarte","Golden","Gomez","Byrd","McClain","Schwartz","Xiong","Garza","Bernal","Shaw","Proctor","Lambert","Rose","Kline","Beard","Moss","Odom","Evans","Flores","Harper","Collins","Moran","Hansen","Day","Ochoa","Cummings","Neal","Solis","Love","Chapman","Green","Walsh","Gould","Howard","Arias","Skinner","Carter","Lin","Ballard","Dougherty","House","Calderon","Hubbard","Page","Mason","Travis","Richard","Archer","Zuniga","Ingram","Richmond","Thomas","Rollins","Marin","Patton","Rivas","Esparza","Hood","Hines","Lopez","Boyle","Guevara","Estrada","Henderson","Rich","Hicks","Everett","Good","Gardner","Sanford","Walls","Hutchinson","Steele","Gray","Mann","Bush","Bean","Simon","Munoz","Coffey","Wood","Morse","Lu","Jimenez","Hanson","Le","Hunter","Romero","Hurley","Montes","Reed","Hill","Maddox","Clayton","Gillespie","Carey","Dunlap","Fox","Orr","Snyder","Jarvis","Mitchell","Dorsey","Miller","Harrell","Gutierrez","Galindo","Bass","Valentine","Cantrell","Jacobs","Parrish","Lam","Yates","Vaughn","Mayo","Fry","Booker","Shannon","Franco","Huffman","Thornton","Davis","Villegas","Yoder","O’brien","Horn","Williamson","Guerra","Case","Medina","Simpson","Mays","Newton","Humphrey","Quinn","Keller","McCormick","Webb","Hurst","Martin","Cortes","Bauer","Felix","Preston","Larson","Randolph","Rodgers","Knox","Cain","Rogers","Robbins","Hoffman","Bullock","McPherson","Lynch","Rivera","Hall","Tran","Hughes","Cruz","Perez","Sullivan","Cherry","Walker","Garrett","Cox","Burton","Robinson","Arellano","Watkins","Stevens","McDonald","Ashley","Pratt","Sloan","Hernandez","Bishop","Henson","Ball","Branch","Pruitt","Doyle","Huff","Espinoza","Brady","Strickland","Grant","Cisneros","Johns","Jensen","Gibbs","McCoy","Blackwell","Mata","Marquez","Jaramillo","Woods","Craig","Mills","Fischer","Wall","Howell","Andersen","Rocha","Chandler","Nguyen","Huerta","Melton","Sanchez","Andrade","Gilbert","Hardy","Roman","Blackburn","Chambers","Shah","Rhodes","Ray","Zhang","Kemp","Cano","Carlson","Reilly","Baxter","Valenzuela","Bartlett","Andrews","Soto","Carpenter","Castaneda","Chavez","Schmidt","Long","McLaughlin","Alvarez","Reid","Tapia","Macdonald","Walter","Small","Young","Villa","Hancock","Krueger","York","Miles","Riley","Escobar","Trejo","Brooks","Bell","Graham","Saunders","Bennett","Lowery","Olson","Coleman","Santos","Whitaker","Dickerson","Singleton","Kerr","Greer","Wu","Cohen","Moyer","Peterson","Malone","Erickson","Flynn","Wilkerson","Peralta","Barron","Schroeder","Norris","Noble","Barnes","Taylor","Bryan","Esquivel","O’Donnell","Collier","Rivers","Cook","Oliver","Acosta","Donovan","Trevino","Schaefer","Salas","Parks","Sandoval","Melendez","Valencia","Wells","Mullins","Blanchard","Eaton","Ruiz","Alfaro","Lozano","Hahn","Buchanan","Parker","Barajas","Lowe","Gallagher","Best","Estes","Savage","Hebert","Dalton","Brock","Pineda","Marsh","Brewer","Prince","Stevenson","Fletcher","Baldwin","Ferguson","Benitez","Maldonado","Novak","Clay","Powell","Norton","Watson","Maynard","Lim","Barton","Cobb","Walton","Ortega","Buckley","Garrison","Elliott","Leblanc","Murray","Gordon","Marshall","Mendoza","Vang","McIntosh","Morrow","Perkins","Hogan","Mayer","Morales","McKee","Roberts","Ponce","Boyer","Bravo","Hampton","Weiss","Barrera","Boone","Quintero","Aguilar","Cooper","Randall","Rojas","Quintana","Griffin","Vance","Dean","Gentry","Schultz","Norman","Ellison","Bridges","Fleming","Rush","Sheppard","Vincent","Curtis","McGee","Marks","Ahmed","Davidson","Pacheco","Stephenson","Hendrix","Moody","Winters","Pena","Duran","Lyons","Potts","Dodson","Fitzpatrick","Suarez","Beck","Barker","McConnell","Ibarra","Charles","Bruce","Gallegos","Barr","Pennington","Clarke","Browning",];
+
+/// Hands out a name for every index, the same index always gets the same name.
+#[derive(Clone, Copy, Debug)]
+pub struct NameGenerator {
+    offset: u64,
+    stride: u64,
+}
+
+impl NameGenerator {
+    pub fn new(seed: u64) -> Self {
+        let combinations = combinations();
+        let mixed = mix(seed);
+        // visiting the combinations with a stride that shares no factor with their count reaches each of them once
+        let mut stride = (mixed >> 32) % combinations;
+        while gcd(stride, combinations) != 1 {
+            stride += 1;
+        }
+
+        Self {
+            offset: mixed % combinations,
+            stride,
+        }
+    }
+
+    /// The name for `index`. Once every combination is used, names start over with a number after them
+    /// ("Reyna Watts 2"), so this never runs out.
+    pub fn name(&self, index: usize) -> String {
+        // `u64`, the product below doesn't fit in the 32 bit `usize` of wasm
+        let combinations = combinations();
+        let round = index as u64 / combinations;
+        let combination =
+            ((index as u64 % combinations) * self.stride + self.offset) % combinations;
+        let first = FIRST_NAMES[(combination % FIRST_NAMES.len() as u64) as usize];
+        let surname = SURNAMES[(combination / FIRST_NAMES.len() as u64) as usize];
+
+        match round {
+            0 => format!("{first} {surname}"),
+            round => format!("{first} {surname} {}", round + 1),
+        }
+    }
+}
+
+fn combinations() -> u64 {
+    (FIRST_NAMES.len() * SURNAMES.len()) as u64
+}
+
+/// splitmix64, so that seeds next to each other still give unrelated orders
+fn mix(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        b => gcd(b, a % b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn same_seed_same_names() {
+        let names = NameGenerator::new(7);
+
+        assert_eq!(names.name(12), NameGenerator::new(7).name(12));
+        assert_ne!(names.name(12), NameGenerator::new(8).name(12));
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let names = NameGenerator::new(42);
+        let unique: HashSet<_> = (0..10_000).map(|index| names.name(index)).collect();
+
+        assert_eq!(unique.len(), 10_000);
+    }
+
+    #[test]
+    fn never_runs_out() {
+        let names = NameGenerator::new(0);
+        let index = combinations() as usize + 3;
+
+        assert_eq!(names.name(index), format!("{} 2", names.name(3)));
+        assert!(!names.name(usize::MAX).is_empty());
+    }
+}
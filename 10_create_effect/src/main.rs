@@ -1,73 +1,93 @@
+use components::{
+    form::{max_len, pattern, required},
+    log_to, use_log, Devtools, DevtoolsPanel, FieldError, FormState, LogConsole, Logger,
+};
 use leptos::html::Input;
-use leptos::*;
+use leptos::prelude::*;
 
 fn main() {
-    mount_to_body(|cx| view! { cx, <App/> })
-}
-
-fn log(cx: Scope, msg: impl std::fmt::Display) {
-    let log = use_context::<RwSignal<Vec<String>>>(cx).unwrap();
-    log.update(|log| log.push(msg.to_string()));
+    mount_to_body(|| view! { <App/> })
 }
 
 #[component]
-fn App(cx: Scope) -> impl IntoView {
-    // Just making a visible log here
+fn App() -> impl IntoView {
+    // Just making a visible log here, it keeps the last 200 entries
     // You can ignore this...
-    let log = create_rw_signal::<Vec<String>>(cx, vec![]);
-    let logged = move || log().join("\n");
-    provide_context(cx, log);
+    Logger::provide(200);
+    // the effects traced through the devtools show up in the panel next to the log
+    Devtools::provide();
 
-    view! { cx,
+    view! {
         <div class="holder">
             <div class="input">
                 <CreateAnEffect/>
                 <ManualVersion/>
                 <EffectVsDerivedSignal/>
             </div>
-            <pre class="output">{logged}</pre>
+            <LogConsole/>
+            <DevtoolsPanel/>
         </div>
     }
 }
 
 #[component]
-fn CreateAnEffect(cx: Scope) -> impl IntoView {
-    let (first, set_first) = create_signal(cx, String::new());
-    let (last, set_last) = create_signal(cx, String::new());
-    let (use_last, set_use_last) = create_signal(cx, true);
+fn CreateAnEffect() -> impl IntoView {
+    // the names are form fields, so they can be validated
+    let form = FormState::new();
+    let first_field = form.field::<String>("").validate(required());
+    let last_field = form
+        .field::<String>("")
+        .validate(max_len(30))
+        .validate(pattern("^[^0-9]*$", "can't contain digits"));
+    let first = move || first_field.text();
+    let last = move || last_field.text();
+    let (use_last, set_use_last) = signal(true);
+    let visible_log = use_log("CreateAnEffect");
+
+    // the effect below is traced, so the devtools panel shows how often it runs and what it reads
+    let devtools = use_context::<Devtools>().expect("devtools to have been provided");
+    devtools.name("use_last", use_last);
 
     // this will add the name to the log
-    // any time one of the source signals changes
-    create_effect(cx, move |_| {
-        log(
-            cx,
-            if use_last() {
-                format!("{}  {}", first(), last())
-            } else {
-                first()
-            },
-        )
-    });
-
-    view! { cx,
-        <h1><code>"create_effect"</code> " Version"</h1>
+    // any time one of the source signals changes, as long as the names are valid
+    // watch the panel: with "Show Last Name" unchecked, the last name drops out of what the effect reads
+    Effect::new(devtools.trace_effect("log name", move |_| {
+        if use_last.get() {
+            if first_field.is_valid() && last_field.is_valid() {
+                log_to!(visible_log, "{}  {}", first(), last())
+            }
+        } else if first_field.is_valid() {
+            log_to!(visible_log, "{}", first())
+        }
+    }));
+
+    view! {
+        <h1><code>"Effect::new"</code> " Version"</h1>
         <form>
             <label>
                 "First Name"
                 <input type="text" name="first" prop:value=first
-                    on:change=move |ev| set_first(event_target_value(&ev))
+                    on:change=move |ev| {
+                        first_field.set_text(event_target_value(&ev));
+                        first_field.touch();
+                    }
                 />
+                <FieldError field=first_field/>
             </label>
             <label>
                 "Last Name"
                 <input type="text" name="last" prop:value=last
-                    on:change=move |ev| set_last(event_target_value(&ev))
+                    on:change=move |ev| {
+                        last_field.set_text(event_target_value(&ev));
+                        last_field.touch();
+                    }
                 />
+                <FieldError field=last_field/>
             </label>
             <label>
                 "Show Last Name"
                 <input type="checkbox" name="use_last" prop:checked=use_last
-                    on:change=move |ev| set_use_last(event_target_checked(&ev))
+                    on:change=move |ev| set_use_last.set(event_target_checked(&ev))
                 />
             </label>
         </form>
@@ -75,14 +95,15 @@ fn CreateAnEffect(cx: Scope) -> impl IntoView {
 }
 
 #[component]
-fn ManualVersion(cx: Scope) -> impl IntoView {
-    let first = create_node_ref::<Input>(cx);
-    let last = create_node_ref::<Input>(cx);
-    let use_last = create_node_ref::<Input>(cx);
+fn ManualVersion() -> impl IntoView {
+    let first = NodeRef::<Input>::new();
+    let last = NodeRef::<Input>::new();
+    let use_last = NodeRef::<Input>::new();
+    let visible_log = use_log("ManualVersion");
 
     let mut prev_name = String::new();
     let on_change = move |_| {
-        log(cx, "      listener");
+        log_to!(visible_log, Debug, "listener");
         let first = first.get().unwrap();
         let last = last.get().unwrap();
         let use_last = use_last.get().unwrap();
@@ -93,12 +114,12 @@ fn ManualVersion(cx: Scope) -> impl IntoView {
         };
 
         if this_one != prev_name {
-            log(cx, &this_one);
+            log_to!(visible_log, "{this_one}");
             prev_name = this_one;
         }
     };
 
-    view! { cx,
+    view! {
         <h1>"Manual Version"</h1>
         <form on:change=on_change>
             <label>
@@ -125,16 +146,16 @@ fn ManualVersion(cx: Scope) -> impl IntoView {
 }
 
 #[component]
-fn EffectVsDerivedSignal(cx: Scope) -> impl IntoView {
-    let (my_value, set_my_value) = create_signal(cx, String::new());
+fn EffectVsDerivedSignal() -> impl IntoView {
+    let (my_value, set_my_value) = signal(String::new());
     // Don't do this.
-    /*let (my_optional_value, set_optional_my_value) = create_signal(cx, Option::<String>::None);
+    /*let (my_optional_value, set_optional_my_value) = signal(Option::<String>::None);
 
-    create_effect(cx, move |_| {
+    Effect::new(move |_| {
         if !my_value.get().is_empty() {
-            set_optional_my_value(Some(my_value.get()));
+            set_optional_my_value.set(Some(my_value.get()));
         } else {
-            set_optional_my_value(None);
+            set_optional_my_value.set(None);
         }
     });*/
 
@@ -142,11 +163,11 @@ fn EffectVsDerivedSignal(cx: Scope) -> impl IntoView {
     let my_optional_value =
         move || (!my_value.with(String::is_empty)).then(|| Some(my_value.get()));
 
-    view! { cx,
+    view! {
         <h1>"Effect vs Derived Signal"</h1>
         <input
             prop:value=my_value
-            on:input= move |ev| set_my_value(event_target_value(&ev))
+            on:input= move |ev| set_my_value.set(event_target_value(&ev))
         />
 
         <p>
@@ -155,7 +176,7 @@ fn EffectVsDerivedSignal(cx: Scope) -> impl IntoView {
             <code>
                 <Show
                     when=move || my_optional_value().is_some()
-                    fallback=|cx| view! { cx, "None" }
+                    fallback=|| view! { "None" }
                 >
                     "Some(\"" {my_optional_value().unwrap()} "\")"
                 </Show>
@@ -165,25 +186,37 @@ fn EffectVsDerivedSignal(cx: Scope) -> impl IntoView {
 }
 
 /*#[component]
-pub fn Show<F, W, IV>(
-    /// The scope the component is running in
-    cx: Scope,
+pub fn Show<W, C>(
     /// The components Show wraps
-    children: Box<dyn Fn(Scope) -> Fragment>,
+    children: TypedChildrenFn<C>,
     /// A closure that returns a bool that determines whether this thing runs
     when: W,
     /// A closure that returns what gets rendered if the when statement is false
-    fallback: F,
+    #[prop(optional, into)]
+    fallback: ViewFn,
 ) -> impl IntoView
 where
-    W: Fn() -> bool + 'static,
-    F: Fn(Scope) -> IV + 'static,
-    IV: IntoView,
+    W: Fn() -> bool + Send + Sync + 'static,
+    C: IntoView + 'static,
 {
-    let memoized_when = create_memo(cx, move |_| when());
+    let memoized_when = Memo::new(move |_| when());
+    let children = children.into_inner();
 
     move || match memoized_when.get() {
-        true => children(cx).into_view(cx),
-        false => fallback(cx).into_view(cx),
+        true => children().into_any(),
+        false => fallback.run().into_any(),
     }
 }*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+}
@@ -1,7 +1,8 @@
-use leptos::*;
+use components::{log_to, use_log, Devtools, DevtoolsPanel, LogConsole, Logger};
+use leptos::prelude::*;
 
 fn main() {
-    mount_to_body(|cx| view! { cx, <App/> })
+    mount_to_body(|| view! { <App/> })
 }
 
 // --- REMEMBER ---
@@ -9,10 +10,18 @@ fn main() {
 // 2. For values in your view template to be reactive, they must be functions: either signals (which implement the Fn traits) or closures.
 
 #[component]
-fn App(cx: Scope) -> impl IntoView {
-    view! { cx,
+fn App() -> impl IntoView {
+    // The effect traced through the devtools shows up in the panel below the demo,
+    // with what it reads and how often it ran.
+    Devtools::provide();
+    // what the effect below logs shows up in the console under the demo
+    Logger::provide(100);
+
+    view! {
         <h1>"Demo"</h1>
         <ReactivityNFunctions/>
+        <LogConsole/>
+        <DevtoolsPanel/>
         <SimpleCounter/>
     }
 }
@@ -22,14 +31,14 @@ fn App(cx: Scope) -> impl IntoView {
 /// When you click the button, the browser calls increment, which updates value via set_value.
 /// And that updates the single text node represented in our view by {value}.
 #[component]
-pub fn SimpleCounter(cx: Scope) -> impl IntoView {
+pub fn SimpleCounter() -> impl IntoView {
     // Created once
-    let (value, set_value) = create_signal(cx, 0);
+    let (value, set_value) = signal(0);
 
     // Handed off to browser as an event listener. On each click the browser calls the function.
     let increment = move |_| set_value.update(|value| *value += 1);
 
-    view! { cx,
+    view! {
         <h2>"Simple Counter"</h2>
         <button on:click=increment>
             {value}
@@ -38,34 +47,50 @@ pub fn SimpleCounter(cx: Scope) -> impl IntoView {
 }
 
 #[component]
-fn ReactivityNFunctions(cx: Scope) -> impl IntoView {
+fn ReactivityNFunctions() -> impl IntoView {
+    let log = use_log("ReactivityNFunctions");
+
     // a signal holds a value, and can be updated
-    let (count, set_count) = create_signal(cx, 0);
+    let (count, set_count) = signal(0);
 
     // a derived signal is a function that accesses other signals
-    let _double_count = move || count() * 2;
-    let count_is_odd = move || count() & 1 == 1;
+    let _double_count = move || count.get() * 2;
+    let count_is_odd = move || count.get() & 1 == 1;
     let text = move || if count_is_odd() { "odd" } else { "even" };
 
+    // tracing the effect puts it in the devtools panel, which shows that it reads `count`:
+    // the derived signals are plain functions, not nodes of the graph
+    let devtools = use_context::<Devtools>().expect("devtools to have been provided");
+    devtools.name("count", count);
+
     // an effect automatically tracks the signals it depends on
-    // and reruns when they change
-    create_effect(cx, move |_| {
-        log!("text = {}", text());
-    });
+    // and reruns when they change, the panel counts how often
+    Effect::new(devtools.trace_effect("log text", move |_| {
+        log_to!(log, "text = {}", text());
+    }));
 
-    view! { cx,
+    view! {
         <h3>"Reactivity and functions demo"</h3>
         <input
             type="text"
-            on:input=move |ev| set_count( 
-                if let Ok(i) = event_target_value(&ev).parse() {
-                    i 
-                } else { 
-                    0 
-                })
+            // anything that doesn't parse as a number counts as 0
+            on:input=move |ev| set_count.set(event_target_value(&ev).parse().unwrap_or_default())
             prop:value=count
         />
         <p>"Value is: "{count}" and "{text}</p>
         <hr/>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+}
@@ -1,4 +1,4 @@
-use leptos::*;
+use leptos::{ev::KeyboardEvent, html::Input, logging::log, prelude::*};
 
 // The easiest way to do testing is to create a wrapping type and test like normally with Rust.
 // Another way is to use the wasm-bindgen-test.
@@ -10,63 +10,379 @@ use leptos::*;
 // This example demonstrates how to do this.
 
 fn main() {
-    mount_to_body(|cx| view! {cx, <TodoApp/>})
+    mount_to_body(|| view! { <TodoApp/>})
 }
 
 #[component]
-pub fn TodoApp(cx: Scope) -> impl IntoView {
-    let (todos, set_todos) = create_signal(cx, Todos(vec![Todo { completed: false }]));
-    // ✅ this has a test associated with it.
-    let num_remaining = move || todos.with(Todos::num_remaining);
+pub fn TodoApp() -> impl IntoView {
+    let (todos, set_todos) = signal(Todos::default());
+    let (filter, set_filter) = signal(Filter::All);
 
-    let add_true = move |_| set_todos.update(|f| f.0.push(Todo { completed: true }));
-    let add_false = move |_| set_todos.update(|f| f.0.push(Todo { completed: false }));
+    // ✅ every one of these has a test associated with it.
+    let num_remaining = move || todos.with(Todos::num_remaining);
+    let num_completed = move || todos.with(Todos::num_completed);
+    let visible = move || todos.with(|todos| todos.filtered(filter.get()));
 
-    create_effect(cx, move |_| {
+    Effect::new(move |_| {
         log!("Remaining to complete in Todos is: {:#?}", num_remaining());
-        log!("Tasks completed: {:#?}", todos().0);
+        log!("Tasks completed: {:#?}", num_completed());
+    });
+
+    let new_todo = NodeRef::<Input>::new();
+    let add = move |ev: KeyboardEvent| {
+        if ev.key() == "Enter" {
+            let input = new_todo.get().expect("input to exist");
+            set_todos.update(|todos| _ = todos.add(&input.value()));
+            input.set_value("");
         }
-    );
+    };
+
+    view! {
+        <header>
+            <h1>"todos"</h1>
+            <input placeholder="What needs to be done?" autofocus node_ref=new_todo on:keydown=add/>
+        </header>
+        <Show when=move || todos.with(|todos| !todos.is_empty())>
+            <section>
+                <label>
+                    <input
+                        type="checkbox"
+                        prop:checked=move || num_remaining() == 0
+                        on:change=move |ev| {
+                            todos.with_untracked(|todos| todos.set_all_completed(event_target_checked(&ev)))
+                        }
+                    />
+                    "Mark all as complete"
+                </label>
+                <ul>
+                    // a row keeps its title and state in signals of its own and updates itself,
+                    // so the id is all <For/> needs to tell the rows apart
+                    <For
+                        each=visible
+                        key=|todo| todo.id
+                        children=move |todo| view! { <TodoItem todo set_todos/> }
+                    />
+                </ul>
+            </section>
+            <footer>
+                <span>{num_remaining} " items left"</span>
+                <FilterButton filter=Filter::All current=filter set_filter/>
+                <FilterButton filter=Filter::Active current=filter set_filter/>
+                <FilterButton filter=Filter::Completed current=filter set_filter/>
+                <Show when=move || { num_completed() > 0 }>
+                    <button on:click=move |_| set_todos.update(Todos::clear_completed)>
+                        "Clear completed"
+                    </button>
+                </Show>
+            </footer>
+        </Show>
+    }
+}
+
+/// A single row, double click the title to edit it.
+/// Enter or leaving the field saves the edit, Escape throws it away.
+#[component]
+fn TodoItem(todo: Todo, set_todos: WriteSignal<Todos>) -> impl IntoView {
+    let id = todo.id;
+    let (editing, set_editing) = signal(false);
+
+    // `autofocus` only counts when the page loads, the edit input shows up long after that
+    let edit_input = NodeRef::<Input>::new();
+    Effect::new(move |_| {
+        if let (true, Some(input)) = (editing.get(), edit_input.get()) {
+            _ = input.focus();
+        }
+    });
+
+    let save = move |title: String| {
+        set_todos.update(|todos| todos.edit(id, &title));
+        set_editing.set(false);
+    };
+    let on_keydown = move |ev: KeyboardEvent| match ev.key().as_str() {
+        "Enter" => save(event_target_value(&ev)),
+        "Escape" => set_editing.set(false),
+        _ => {}
+    };
 
+    view! {
+        <li>
+            <Show
+                when=move || editing.get()
+                fallback=move || view! {
+                    <input
+                        type="checkbox"
+                        prop:checked=todo.completed
+                        on:change=move |_| todo.toggle()
+                    />
+                    <label on:dblclick=move |_| set_editing.set(true)>{todo.title}</label>
+                    <button on:click=move |_| set_todos.update(|todos| todos.remove(id))>"×"</button>
+                }
+            >
+                <input
+                    node_ref=edit_input
+                    prop:value=todo.title
+                    on:keydown=on_keydown
+                    on:blur=move |ev| {
+                        if editing.get_untracked() {
+                            save(event_target_value(&ev))
+                        }
+                    }
+                />
+            </Show>
+        </li>
+    }
+}
 
-    view! {cx,
-        <p>"Is the task finished?"</p>
-        <button on:click=add_true>"True"</button>
-        <button on:click=add_false>"False"</button>
-        <p>"Tasks remaining: "{num_remaining}</p>
+#[component]
+fn FilterButton(
+    filter: Filter,
+    current: ReadSignal<Filter>,
+    set_filter: WriteSignal<Filter>,
+) -> impl IntoView {
+    view! {
+        <button
+            class:selected=move || current.get() == filter
+            on:click=move |_| set_filter.set(filter)
+        >
+            {format!("{filter:?}")}
+        </button>
     }
 }
 
-#[derive(Clone)]
-pub struct Todos(Vec<Todo>);
+/// Which todos are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Filter {
+    #[default]
+    All,
+    Active,
+    Completed,
+}
+
+impl Filter {
+    pub fn matches(self, todo: &Todo) -> bool {
+        match self {
+            Filter::All => true,
+            Filter::Active => !todo.completed.get(),
+            Filter::Completed => todo.completed.get(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Todos {
+    todos: Vec<Todo>,
+    // ids are never reused, even after the todo with the highest one was removed
+    next_id: usize,
+}
 
 // We can test the functions implemented in here!
 impl Todos {
+    /// Untitled todos, completed or not, mostly useful for tests.
     pub fn new(v: Vec<bool>) -> Self {
         Self {
-            0: v.into_iter()
-                .map(|boolean| Todo { completed: boolean })
+            next_id: v.len(),
+            todos: v
+                .into_iter()
+                .enumerate()
+                .map(|(id, completed)| Todo {
+                    id,
+                    title: RwSignal::new(String::new()),
+                    completed: RwSignal::new(completed),
+                })
                 .collect(),
         }
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.todos.is_empty()
+    }
+
     pub fn num_remaining(&self) -> usize {
-        self.0.iter().filter(|todo| !todo.completed).count()
+        self.todos
+            .iter()
+            .filter(|todo| !todo.completed.get())
+            .count()
+    }
+
+    pub fn num_completed(&self) -> usize {
+        self.todos.len() - self.num_remaining()
+    }
+
+    /// Adds a new, active todo and returns its id.
+    /// The title is trimmed, a blank one adds nothing.
+    pub fn add(&mut self, title: &str) -> Option<usize> {
+        let title = title.trim();
+        if title.is_empty() {
+            return None;
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.todos.push(Todo {
+            id,
+            title: RwSignal::new(title.to_string()),
+            completed: RwSignal::new(false),
+        });
+        Some(id)
+    }
+
+    pub fn toggle(&self, id: usize) {
+        if let Some(todo) = self.get(id) {
+            todo.toggle();
+        }
+    }
+
+    pub fn set_all_completed(&self, completed: bool) {
+        for todo in &self.todos {
+            todo.completed.set(completed);
+        }
+    }
+
+    /// Renames a todo, like in TodoMVC a blank title removes it.
+    pub fn edit(&mut self, id: usize, title: &str) {
+        let title = title.trim();
+        if title.is_empty() {
+            self.remove(id);
+        } else if let Some(todo) = self.get(id) {
+            todo.title.set(title.to_string());
+        }
+    }
+
+    pub fn remove(&mut self, id: usize) {
+        self.todos.retain(|todo| todo.id != id);
+    }
+
+    pub fn clear_completed(&mut self) {
+        self.todos.retain(|todo| !todo.completed.get_untracked());
+    }
+
+    pub fn filtered(&self, filter: Filter) -> Vec<Todo> {
+        self.todos
+            .iter()
+            .filter(|todo| filter.matches(todo))
+            .copied()
+            .collect()
+    }
+
+    fn get(&self, id: usize) -> Option<&Todo> {
+        self.todos.iter().find(|todo| todo.id == id)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <TodoApp/> });
+
+        insta::assert_snapshot!(html);
+    }
+
     #[test]
     fn test_remaining() {
         let td = Todos::new(vec![true, false, false, true, true, false]);
 
         assert_eq!(td.num_remaining(), 3);
     }
+
+    #[test]
+    fn test_add() {
+        let mut td = Todos::default();
+
+        assert_eq!(td.add("  milk "), Some(0));
+        assert_eq!(td.add("eggs"), Some(1));
+        assert_eq!(td.add("   "), None);
+        assert_eq!(td.filtered(Filter::All)[0].title.get(), "milk");
+        assert_eq!(td.num_remaining(), 2);
+    }
+
+    #[test]
+    fn test_ids_are_not_reused() {
+        let mut td = Todos::default();
+        td.add("milk");
+        let eggs = td.add("eggs").unwrap();
+        td.remove(eggs);
+
+        assert_ne!(td.add("bread"), Some(eggs));
+    }
+
+    #[test]
+    fn test_toggle() {
+        let td = Todos::new(vec![false, false]);
+        td.toggle(1);
+
+        assert_eq!(td.num_completed(), 1);
+        td.toggle(1);
+        assert_eq!(td.num_completed(), 0);
+    }
+
+    #[test]
+    fn test_set_all_completed() {
+        let td = Todos::new(vec![true, false, false]);
+        td.set_all_completed(true);
+
+        assert_eq!(td.num_remaining(), 0);
+        td.set_all_completed(false);
+        assert_eq!(td.num_completed(), 0);
+    }
+
+    #[test]
+    fn test_edit() {
+        let mut td = Todos::default();
+        let id = td.add("milk").unwrap();
+        td.edit(id, " oat milk ");
+
+        assert_eq!(td.filtered(Filter::All)[0].title.get(), "oat milk");
+        td.edit(id, "");
+        assert!(td.is_empty());
+    }
+
+    #[test]
+    fn test_rows_follow_their_todo() {
+        let mut td = Todos::default();
+        let id = td.add("milk").unwrap();
+        let row = td.filtered(Filter::All)[0];
+        td.edit(id, "oat milk");
+        td.toggle(id);
+
+        assert_eq!(row.title.get(), "oat milk");
+        assert!(row.completed.get());
+    }
+
+    #[test]
+    fn test_clear_completed() {
+        let mut td = Todos::new(vec![true, false, true]);
+        td.clear_completed();
+
+        assert_eq!(td.filtered(Filter::All).len(), 1);
+        assert_eq!(td.num_completed(), 0);
+    }
+
+    #[test]
+    fn test_filtered() {
+        let td = Todos::new(vec![true, false, false, true, true, false]);
+
+        assert_eq!(td.filtered(Filter::All).len(), 6);
+        assert_eq!(td.filtered(Filter::Active).len(), 3);
+        assert!(td
+            .filtered(Filter::Completed)
+            .iter()
+            .all(|todo| todo.completed.get()));
+    }
 }
 
-#[derive(Debug, Clone)]
+/// A todo's title and state are signals, so its row follows them without being rendered again.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Todo {
-    pub completed: bool,
+    pub id: usize,
+    pub title: RwSignal<String>,
+    pub completed: RwSignal<bool>,
+}
+
+impl Todo {
+    pub fn toggle(&self) {
+        self.completed.update(|completed| *completed = !*completed);
+    }
 }
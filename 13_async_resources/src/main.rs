@@ -1,61 +1,66 @@
-use gloo_timers::future::TimeoutFuture;
-use leptos::*;
+use std::time::Duration;
+
+use components::sleep;
+use leptos::prelude::*;
 
 // Here we define an async function
 // This could be anything: a network request, database read, etc.
 // In this case we just multiply a number by 10
 async fn load_data(value: i32) -> i32 {
     // fake a one-second delay
-    TimeoutFuture::new(1_000).await;
+    sleep(Duration::from_secs(1)).await;
     value * 10
 }
 
 #[component]
-fn App(cx: Scope) -> impl IntoView {
+fn App() -> impl IntoView {
     // this count is our synchronous, local state
-    let (count, set_count) = create_signal(cx, 0);
+    let (count, set_count) = signal(0);
 
-    // create_resource takes two arguments after its scope
-    let async_data = create_resource(
-        cx,
-        // the first is the "source signal"
-        count,
-        // the second is the loader
-        // it takes the source signal's value as its argument
-        // and does some async work. every time the count changes this will run.
-        |value| async move { load_data(value).await },
+    // `Resource::new` takes two arguments:
+    // - a source, whose value the resource loads for. every signal read in it is tracked,
+    //   so every time the count changes this will run
+    // - a fetcher, an async function that takes the value of the source and loads the data
+    //
+    // a `Resource` wants a `Send` future, which `sleep` is. the timers of `gloo_timers` are not,
+    // a future like that needs a `LocalResource`, which takes a single fetcher that reads the signals itself
+    //
+    // we also hand back the value we loaded for, this lets us tell when the data is stale
+    let async_data = Resource::new(
+        move || count.get(),
+        |value| async move { (value, load_data(value).await) },
     );
     // whenever the source signal changes, the loader reloads
 
     // you can also create resources that only load once
     // just return the unit type () from the source signal
     // that doesn't depend on anything: we just load it once
-    let stable = create_resource(cx, || (), |_| async move { load_data(1).await });
+    let stable = Resource::new(|| (), |_| async move { load_data(1).await });
 
-    // we can access the resource values with .read()
+    // we can access the resource values with .get()
     // this will reactively return None before the Future has resolved
     // and update to Some(T) when it has resolved
     let async_result = move || {
         async_data
-            .read(cx)
-            .map(|value| format!("Server returned {value:?}"))
+            .get()
+            .map(|data| format!("Server returned {:?}", data.1))
             // This loading state will only show before the first load
             .unwrap_or_else(|| "Loading...".into())
     };
 
-    // the resource's loading() method gives us a
-    // signal to indicate whether it's currently loading
-    let loading = async_data.loading();
+    // while it reloads, a resource keeps returning the last value it loaded,
+    // so it's loading whenever that value was loaded for a different count
+    let loading = move || async_data.get().is_none_or(|data| data.0 != count.get());
     let is_loading = move || if loading() { "Loading..." } else { "Idle." };
 
-    view! { cx,
+    view! {
         <button on:click=move |_| {
             set_count.update(|n| *n += 1);
         }>"Click me"</button>
         <p>
             <code>"stable"</code>
             ": "
-            {move || stable.read(cx)}
+            {move || stable.get()}
         </p>
         <p>
             <code>"count"</code>
@@ -73,5 +78,18 @@ fn App(cx: Scope) -> impl IntoView {
 }
 
 fn main() {
-    leptos::mount_to_body(|cx| view! { cx, <App/> })
+    leptos::mount::mount_to_body(|| view! { <App/> })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_resolved;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_resolved(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
 }
@@ -1,26 +1,24 @@
-use leptos::*;
-use gloo_timers::future::TimeoutFuture;
+use std::time::Duration;
+
+use components::sleep;
+use leptos::prelude::*;
 
 async fn important_api_call(name: String) -> String {
-    TimeoutFuture::new(1_000).await;
+    sleep(Duration::from_secs(1)).await;
     name.to_ascii_uppercase()
 }
 
 #[component]
-fn App(cx: Scope) -> impl IntoView {
-    let (name, set_name) = create_signal(cx, "Bill".to_string());
+fn App() -> impl IntoView {
+    let (name, set_name) = signal("Bill".to_string());
 
     // this will reload every time `name` changes
-    let async_data = create_resource(
-        cx,
-        name,
-        |name| async move { important_api_call(name).await },
-    );
+    let async_data = Resource::new(move || name.get(), important_api_call);
 
-    view! { cx,
+    view! {
         <input
             on:input=move |ev| {
-                set_name(event_target_value(&ev));
+                set_name.set(event_target_value(&ev));
             }
             prop:value=name
         />
@@ -28,18 +26,33 @@ fn App(cx: Scope) -> impl IntoView {
         <Suspense
             // the fallback will show whenever a resource
             // read "under" the suspense is loading
-            fallback=move || view! { cx, <p>"Loading..."</p> }
+            fallback=move || view! { <p>"Loading..."</p> }
         >
             // the children will be rendered once initially,
             // and then whenever any resources has been resolved
             <p>
                 "Your shouting name is "
-                {move || async_data.read(cx)}
+                // `Suspend` lets us simply `.await` the resource,
+                // this is what registers it with the <Suspense/>
+                {move || Suspend::new(async move { async_data.await })}
             </p>
         </Suspense>
     }
 }
 
 fn main() {
-    leptos::mount_to_body(|cx| view! { cx, <App/> })
+    leptos::mount::mount_to_body(|| view! { <App/> })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_resolved;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_resolved(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
 }
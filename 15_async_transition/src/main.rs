@@ -1,8 +1,10 @@
-use gloo_timers::future::TimeoutFuture;
-use leptos::*;
+use std::time::Duration;
+
+use components::sleep;
+use leptos::prelude::*;
 
 async fn important_api_call(id: usize) -> String {
-    TimeoutFuture::new(1_000).await;
+    sleep(Duration::from_secs(1)).await;
     match id {
         0 => "Alice",
         1 => "Bob",
@@ -13,33 +15,35 @@ async fn important_api_call(id: usize) -> String {
 }
 
 #[component]
-fn App(cx: Scope) -> impl IntoView {
-    let (tab, set_tab) = create_signal(cx, 4);
+fn App() -> impl IntoView {
+    let (tab, set_tab) = signal(4);
+    // <Transition/> tells us whether it's waiting on new data through this signal
+    let (pending, set_pending) = signal(false);
 
     // this will reload every time `tab` changes
-    let user_data = create_resource(cx, tab, |tab| async move { important_api_call(tab).await });
+    let user_data = Resource::new(move || tab.get(), important_api_call);
 
-    view! { cx,
+    view! {
         <div class="buttons">
             <button
-                on:click=move |_| set_tab(0)
-                class:selected=move || tab() == 0
+                on:click=move |_| set_tab.set(0)
+                class:selected=move || tab.get() == 0
             >
                 "Tab A"
             </button>
             <button
-                on:click=move |_| set_tab(1)
-                class:selected=move || tab() == 1
+                on:click=move |_| set_tab.set(1)
+                class:selected=move || tab.get() == 1
             >
                 "Tab B"
             </button>
             <button
-                on:click=move |_| set_tab(2)
-                class:selected=move || tab() == 2
+                on:click=move |_| set_tab.set(2)
+                class:selected=move || tab.get() == 2
             >
                 "Tab C"
             </button>
-            {move || if user_data.loading().get() {
+            {move || if pending.get() {
                 "Loading..."
             } else {
                 ""
@@ -49,15 +53,29 @@ fn App(cx: Scope) -> impl IntoView {
             // the fallback will show initially
             // on subsequent reloads, the current child will
             // continue showing
-            fallback=move || view! { cx, <p>"Loading..."</p> }
+            fallback=move || view! { <p>"Loading..."</p> }
+            set_pending
         >
             <p>
-                {move || user_data.read(cx)}
+                {move || Suspend::new(async move { user_data.await })}
             </p>
         </Transition>
     }
 }
 
 fn main() {
-    mount_to_body(|cx| view! { cx, <App/> })
+    mount_to_body(|| view! { <App/> })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_resolved;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_resolved(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
 }
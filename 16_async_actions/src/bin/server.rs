@@ -0,0 +1,13 @@
+use leptos::config::LeptosOptions;
+
+/// Serves the page and the todo server functions, the todos are kept in `todos.db`.
+/// The wasm bundle that hydrates the page is expected in `pkg/`, see the README for how to build it.
+#[tokio::main]
+async fn main() {
+    let options = LeptosOptions::builder()
+        .output_name(env!("CARGO_PKG_NAME"))
+        .site_root(env!("CARGO_MANIFEST_DIR"))
+        .build();
+
+    ssr_server::serve(options, actions::shell).await;
+}
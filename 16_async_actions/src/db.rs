@@ -0,0 +1,133 @@
+use std::sync::Mutex;
+
+use leptos::prelude::ServerFnError;
+use rusqlite::{params, Connection};
+
+use crate::Todo;
+
+/// The database lives next to the lesson, so the todos survive a restart of the server.
+#[cfg(not(test))]
+const DB_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/todos.db");
+/// The tests start from an empty database, and leave the todos of the lesson alone.
+#[cfg(test)]
+const DB_PATH: &str = ":memory:";
+
+/// The one connection the server shares between requests, opened by the first of them.
+static DB: Mutex<Option<Connection>> = Mutex::new(None);
+
+/// Runs `query` on the shared connection, on a thread where blocking is fine.
+/// If the database can't be opened the request fails, and the next one tries again.
+async fn with_db<T, Q>(query: Q) -> Result<T, ServerFnError>
+where
+    T: Send + 'static,
+    Q: FnOnce(&Connection) -> Result<T, ServerFnError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let mut db = DB.lock()?;
+        let conn = match &mut *db {
+            Some(conn) => conn,
+            closed => closed.insert(open(DB_PATH)?),
+        };
+        query(conn)
+    })
+    .await?
+}
+
+fn open(path: &str) -> Result<Connection, ServerFnError> {
+    let conn = Connection::open(path)?;
+    create_table(&conn)?;
+    Ok(conn)
+}
+
+fn create_table(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS todos (id INTEGER PRIMARY KEY, text TEXT NOT NULL)",
+        (),
+    )?;
+    Ok(())
+}
+
+pub async fn add_todo(text: String) -> Result<i64, ServerFnError> {
+    with_db(move |conn| insert(conn, &text)).await
+}
+
+pub async fn list_todos() -> Result<Vec<Todo>, ServerFnError> {
+    with_db(|conn| Ok(select_all(conn)?)).await
+}
+
+pub async fn delete_todo(id: i64) -> Result<(), ServerFnError> {
+    with_db(move |conn| Ok(delete(conn, id)?)).await
+}
+
+/// Stores the todo and returns its ID. A todo without any text is refused.
+fn insert(conn: &Connection, text: &str) -> Result<i64, ServerFnError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ServerFnError::new("a todo needs some text"));
+    }
+    conn.execute("INSERT INTO todos (text) VALUES (?1)", params![text])?;
+    Ok(conn.last_insert_rowid())
+}
+
+fn select_all(conn: &Connection) -> rusqlite::Result<Vec<Todo>> {
+    let mut stmt = conn.prepare("SELECT id, text FROM todos ORDER BY id")?;
+    let todos = stmt.query_map((), |row| {
+        Ok(Todo {
+            id: row.get(0)?,
+            text: row.get(1)?,
+        })
+    })?;
+    todos.collect()
+}
+
+fn delete(conn: &Connection, id: i64) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM todos WHERE id = ?1", params![id])?;
+    Ok(())
+}
+
+// The database only exists on the server, the tests turn the `ssr` feature on, see Cargo.toml.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        create_table(&conn).unwrap();
+        conn
+    }
+
+    fn texts(conn: &Connection) -> Vec<String> {
+        select_all(conn)
+            .unwrap()
+            .into_iter()
+            .map(|todo| todo.text)
+            .collect()
+    }
+
+    #[test]
+    fn stores_and_deletes_todos() {
+        let conn = memory_db();
+        let milk = insert(&conn, " Buy milk ").unwrap();
+        let bread = insert(&conn, "Buy bread").unwrap();
+        assert_ne!(milk, bread);
+        assert_eq!(texts(&conn), ["Buy milk", "Buy bread"]);
+
+        delete(&conn, milk).unwrap();
+        assert_eq!(texts(&conn), ["Buy bread"]);
+        // deleting what is already gone is fine
+        delete(&conn, milk).unwrap();
+    }
+
+    #[test]
+    fn refuses_empty_todos() {
+        let conn = memory_db();
+        assert!(insert(&conn, "").is_err());
+        assert!(insert(&conn, "   ").is_err());
+        assert!(texts(&conn).is_empty());
+    }
+
+    #[test]
+    fn fails_instead_of_panicking_on_a_bad_path() {
+        assert!(open("/no/such/folder/todos.db").is_err());
+    }
+}
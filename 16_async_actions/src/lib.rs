@@ -0,0 +1,155 @@
+use leptos::{html::Input, prelude::*};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+mod db;
+
+/// The whole page, as the server sends it.
+/// Only the `<body>` is hydrated, the `<head>` carries the scripts that load the wasm bundle.
+#[cfg(feature = "ssr")]
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    use leptos::hydration::HydrationScripts;
+
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <title>"Leptos Learn"</title>
+                <meta charset="utf-8"/>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+
+/// Entry point of the wasm bundle when the page was rendered on the server.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    leptos::mount::hydrate_body(App);
+}
+
+/// A todo as it is stored in the database.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Todo {
+    pub id: i64,
+    pub text: String,
+}
+
+// Server functions are async functions that only ever run on the server.
+// In the browser, `#[server]` turns them into a request to `/api/...`,
+// so calling one from the client is a network round trip to the SQLite database.
+//
+// Think of `add_todo` as a mutation: some imperative async action you run,
+// whereas a resource would be some async data you load
+#[server]
+pub async fn add_todo(text: String) -> Result<i64, ServerFnError> {
+    db::add_todo(text).await
+}
+
+#[server]
+pub async fn list_todos() -> Result<Vec<Todo>, ServerFnError> {
+    db::list_todos().await
+}
+
+#[server]
+pub async fn delete_todo(id: i64) -> Result<(), ServerFnError> {
+    db::delete_todo(id).await
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    // an action takes an async function with single argument
+    // it can be a simple type, a struct, or ()
+    let add_todo = Action::new(|input: &String| {
+        // the input is a reference, but we need the Future to own it
+        // this is important: we need to clone and move into the Future
+        // so it has a 'static lifetime
+        let input = input.to_owned();
+        async move { add_todo(input).await }
+    });
+    let delete_todo = Action::new(|id: &i64| delete_todo(*id));
+
+    // actions provide a bunch of synchronous, reactive variables
+    // that tell us different things about the state of the action
+    let submitted = add_todo.input();
+    let pending = add_todo.pending();
+    let todo_id = add_todo.value();
+
+    // every finished action bumps its version, so the list reloads after each of them
+    let todos = Resource::new(
+        move || (add_todo.version().get(), delete_todo.version().get()),
+        |_| list_todos(),
+    );
+
+    let input_ref = NodeRef::<Input>::new();
+
+    view! {
+        <form
+            on:submit=move |ev| {
+                ev.prevent_default(); // don't reload the page...
+                let input = input_ref.get().expect("input to exist");
+                add_todo.dispatch(input.value());
+            }
+        >
+            <label>
+                "What do you need to do?"
+                <input type="text"
+                    node_ref=input_ref
+                />
+            </label>
+            <button type="submit">"Add Todo"</button>
+        </form>
+        <p>{move || pending.get().then_some("Loading...")}</p>
+        <p>
+            "Submitted: "
+            <code>{move || format!("{:#?}", submitted.get())}</code>
+        </p>
+        <p>
+            "Pending: "
+            <code>{move || format!("{:#?}", pending.get())}</code>
+        </p>
+        <p>
+            "Todo ID: "
+            <code>{move || format!("{:#?}", todo_id.get())}</code>
+        </p>
+        <Transition fallback=|| view! { <p>"Loading todos..."</p> }>
+            <ul>
+                {move || Suspend::new(async move {
+                    todos.await.map(|todos| {
+                        todos
+                            .into_iter()
+                            .map(|todo| {
+                                let id = todo.id;
+                                view! {
+                                    <li>
+                                        {todo.text} " "
+                                        <button on:click=move |_| {
+                                            delete_todo.dispatch(id);
+                                        }>"Delete"</button>
+                                    </li>
+                                }
+                            })
+                            .collect_view()
+                    })
+                })}
+            </ul>
+        </Transition>
+    }
+}
+
+// The snapshot is rendered by the server, the tests turn the `ssr` feature on, see Cargo.toml.
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn app_snapshot() {
+        let page = ssr_server::render("/", || view! { <App/> }).await;
+
+        insta::assert_snapshot!(page.html);
+    }
+}
@@ -1,4 +1,5 @@
-use leptos::*;
+use components::{use_session, LoginForm, Session};
+use leptos::prelude::*;
 
 // As you build components you may occasionally find yourself wanting to "project" children through
 // multiple layers of components.
@@ -6,49 +7,49 @@ use leptos::*;
 /// This is pretty straightforward: when the user is logged in, we want to show children.
 /// If the user is not logged in, we want to show fallback.
 #[component] 
-pub fn FirstDemo(cx: Scope) -> impl IntoView {
-    view! { cx,
+pub fn FirstDemo() -> impl IntoView {
+    let session = use_session();
+
+    view! {
         <h2>"LoggedIn"</h2>
-        <LoggedIn fallback=|cx| {
-            view! { cx, <p>"NOT LOGGED IN!!"</p> }
-        }>"Logged IN!"</LoggedIn>
+        <LoggedIn fallback=|| {
+            view! {
+                <p>"NOT LOGGED IN!! Try alice / wonderland."</p>
+                <LoginForm on_login=|_| ()/>
+            }
+        }>
+            <p>"Logged IN as " {move || session.user()} "!"</p>
+            <button on:click=move |_| session.logout()>"Log out"</button>
+        </LoggedIn>
     }
 }
 
 /// In other words, we want to pass the children of `<LoggedIn/>` through the `<Suspense/>` component to become the children of the `<Show/>`. 
 /// However, both `<Suspense/>` and `<Show/>` take ChildrenFn, i.e.,
 /// their children should implement the Fn type so they can be called multiple times with only an immutable reference.
-/// We can solve this problem by using the `store_value` primitive.
+/// We can solve this problem by using the `StoredValue` primitive.
 /// This works because <Show/> and <Suspense/> only need an immutable refernce to their children (which `.with_value()` can give it), not ownership.
 /// In other cases, you may need to project owned props, this is demonstrated below this component.
 #[component]
-pub fn LoggedIn<F, IV>(cx: Scope, fallback: F, children: ChildrenFn) -> impl IntoView
+pub fn LoggedIn<F, IV>(fallback: F, children: ChildrenFn) -> impl IntoView
 where
-    F: Fn(Scope) -> IV + 'static,
-    IV: IntoView,
+    F: Fn() -> IV + Send + Sync + 'static,
+    IV: IntoView + 'static,
 {
-    // Get a random number wrapped inside a signal just for demonstration purposes
-    let mut seed = get_rand(); 
-    let (rand, set_rand) = create_signal(cx, get_rand_wseed(seed));
-    let check = move || rand() < 50;
-    let click = move |_| {
-        seed += 1;
-        set_rand(get_rand_wseed(seed))
-    };
-
-    
+    // The session is provided by the `App`, `LoggedIn` only reads it.
+    let session = use_session();
+    let check = move || session.is_logged_in();
+
     // This essentially stores a value in the reactive system, handing ownership off to the framework in exchange 
     // for a reference that is, like signals: `Copy` and `'static`, which we can access or modify through certain methods.
-    let fallback = store_value(cx, fallback);
-    let children = store_value(cx, children);
+    let fallback = StoredValue::new(fallback);
+    let children = StoredValue::new(children);
 
     // Note the ˙.with_value()` syntax to get the value out of StoredValue.
-    view! { cx,
-        <button on:click=click>"Change random NUMBER"</button>
-        <p>"Number is: " {rand} "! Numbers under 50 get logged in!"</p>
+    view! {
         <Suspense fallback=|| ()>
-            <Show when=check fallback=move |cx| fallback.with_value(|fallback| fallback(cx))>
-                {children.with_value(|children| children(cx))}
+            <Show when=check fallback=move || fallback.with_value(|fallback| fallback())>
+                {children.with_value(|children| children())}
             </Show>
         </Suspense>
     }
@@ -60,9 +61,9 @@ where
 /// In this case, the `clone:` syntax comes in handy. Calling `clone:name` will clone name before moving it into `<Inner/>`'s  children,
 /// which solves our ownership issue.
 #[component]
-pub fn SecondDemo(cx: Scope) -> impl IntoView {
+pub fn SecondDemo() -> impl IntoView {
     let name = "Alice".to_string();
-    view! { cx,
+    view! {
         <h2>"OuterInnerInmost"</h2>
         <Outer>
             <Inner clone:name>
@@ -73,39 +74,45 @@ pub fn SecondDemo(cx: Scope) -> impl IntoView {
 }
 
 #[component]
-pub fn Outer(cx: Scope, children: ChildrenFn) -> impl IntoView {
-    children(cx)
+pub fn Outer(children: ChildrenFn) -> impl IntoView {
+    children()
 }
 
 #[component]
-pub fn Inner(cx: Scope, children: ChildrenFn) -> impl IntoView {
-    children(cx)
+pub fn Inner(children: ChildrenFn) -> impl IntoView {
+    children()
 }
 
 #[component]
-pub fn Inmost(cx: Scope, name: String) -> impl IntoView {
-    view! { cx, <p>{name}</p> }
+pub fn Inmost(name: String) -> impl IntoView {
+    view! { <p>{name}</p> }
 }
 
 
-// ---- Regular RUST ---- //
+fn main() {
+    mount_to_body(|| view! { <App/> });
+}
 
-use tinyrand::{Rand, Seeded, StdRand};
+#[component]
+fn App() -> impl IntoView {
+    // who is logged in, for every `<LoggedIn/>` below
+    Session::provide();
 
-fn get_rand_wseed(seed: u64) -> usize {
-    let mut rand = StdRand::seed(seed);
-    rand.next_lim_usize(100)
+    view! {
+        <FirstDemo/>
+        <SecondDemo/>
+    }
 }
 
-fn get_rand() -> u64 {
-    let mut rand = StdRand::default();
-    rand.next_u64()
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
 
-fn main() {
-    mount_to_body(|cx| {
-        view! { cx, 
-            <FirstDemo/>
-            <SecondDemo/> }
-    });
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
 }
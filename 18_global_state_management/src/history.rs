@@ -0,0 +1,212 @@
+use leptos::{prelude::*, web_sys::js_sys::Date};
+use reactive_stores::Store;
+
+/// Changes of the same kind that come closer together than this many milliseconds
+/// end up in one undo step, so undo takes back a typed word and not a single letter.
+const COALESCE_MS: f64 = 1000.0;
+
+/// How many undo steps are kept.
+const LIMIT: usize = 100;
+
+/// Snapshots of a value from before it was changed, plus the ones undo took back.
+#[derive(Clone, Debug)]
+pub struct History<T> {
+    undo: Vec<T>,
+    redo: Vec<T>,
+    /// The kind and time of the last recorded change, if it can be merged with the next one.
+    last: Option<(&'static str, f64)>,
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last: None,
+        }
+    }
+}
+
+impl<T> History<T> {
+    /// Keeps `snapshot`, the value from right before a change, as the next undo step.
+    ///
+    /// Changes with the same `kind` that follow each other within [`COALESCE_MS`] share
+    /// the snapshot of the first one. A change without a kind is always its own step.
+    pub fn record(&mut self, snapshot: T, kind: Option<&'static str>, now: f64) {
+        let merge = matches!(
+            (kind, self.last),
+            (Some(kind), Some((last, at))) if kind == last && now - at < COALESCE_MS
+        );
+        if !merge {
+            self.undo.push(snapshot);
+            if self.undo.len() > LIMIT {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last = kind.map(|kind| (kind, now));
+    }
+
+    /// The value to go back to, `current` is kept for redo.
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        self.last = None;
+        Some(previous)
+    }
+
+    /// The value undo took back, `current` is kept for undo again.
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        self.last = None;
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+/// A [`History`] for the value in `state`. Like the store it wraps, it is `Copy` and can be
+/// provided through context.
+///
+/// Nothing is recorded on its own: call [`StateHistory::record`] right before changing the state.
+pub struct StateHistory<T: Send + Sync + 'static> {
+    state: Store<T>,
+    history: RwSignal<History<T>>,
+}
+
+impl<T: Send + Sync + 'static> Clone for StateHistory<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for StateHistory<T> {}
+
+impl<T: Clone + Send + Sync + 'static> StateHistory<T> {
+    pub fn new(state: Store<T>) -> Self {
+        Self {
+            state,
+            history: RwSignal::new(History::default()),
+        }
+    }
+
+    /// Records the current state as an undo step of its own.
+    pub fn record(&self) {
+        self.record_kind(None);
+    }
+
+    /// Records the current state, merged with the steps of the same `kind` right before it.
+    pub fn record_typing(&self, kind: &'static str) {
+        self.record_kind(Some(kind));
+    }
+
+    fn record_kind(&self, kind: Option<&'static str>) {
+        let snapshot = self.state.get_untracked();
+        self.history
+            .update(|history| history.record(snapshot, kind, Date::now()));
+    }
+
+    pub fn undo(&self) {
+        let current = self.state.get_untracked();
+        if let Some(previous) = self
+            .history
+            .try_update(|history| history.undo(current))
+            .flatten()
+        {
+            self.state.set(previous);
+        }
+    }
+
+    pub fn redo(&self) {
+        let current = self.state.get_untracked();
+        if let Some(next) = self
+            .history
+            .try_update(|history| history.redo(current))
+            .flatten()
+        {
+            self.state.set(next);
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.history.with(History::can_undo)
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.history.with(History::can_redo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_and_redo_walk_the_steps() {
+        let mut history = History::default();
+        history.record(0, None, 0.0);
+        history.record(1, None, 10.0);
+
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), Some(0));
+        assert_eq!(history.undo(0), None);
+        assert_eq!(history.redo(0), Some(1));
+        assert_eq!(history.redo(1), Some(2));
+        assert_eq!(history.redo(2), None);
+    }
+
+    #[test]
+    fn a_new_change_drops_the_redo_steps() {
+        let mut history = History::default();
+        history.record(0, None, 0.0);
+        history.undo(1);
+
+        history.record(0, None, 10.0);
+
+        assert!(!history.can_redo());
+        assert!(history.can_undo());
+    }
+
+    #[test]
+    fn quick_changes_of_one_kind_are_one_step() {
+        let mut history = History::default();
+        history.record("", Some("name"), 0.0);
+        history.record("a", Some("name"), 400.0);
+        history.record("ab", Some("name"), 800.0);
+        // a pause starts a new step
+        history.record("abc", Some("name"), 5000.0);
+
+        assert_eq!(history.undo("abcd"), Some("abc"));
+        assert_eq!(history.undo("abc"), Some(""));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn other_changes_are_never_merged() {
+        let mut history = History::default();
+        history.record(0, Some("name"), 0.0);
+        history.record(1, Some("count"), 10.0);
+        history.record(2, None, 20.0);
+        history.record(3, None, 30.0);
+
+        assert_eq!(history.undo.len(), 4);
+    }
+
+    #[test]
+    fn old_steps_are_dropped() {
+        let mut history = History::default();
+        for n in 0..LIMIT + 5 {
+            history.record(n, None, n as f64);
+        }
+
+        assert_eq!(history.undo.len(), LIMIT);
+        assert_eq!(history.undo[0], 5);
+    }
+}
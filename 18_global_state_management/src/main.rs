@@ -1,4 +1,13 @@
-use leptos::*;
+use history::StateHistory;
+use leptos::{
+    ev::{KeyboardEvent, SubmitEvent},
+    prelude::*,
+};
+use reactive_stores::{Store, StoreFieldIterator};
+use serde::{Deserialize, Serialize};
+
+mod history;
+mod sync;
 
 // So far, we've only been working with local state in components
 // We've only seen how to communicate between parent and child components
@@ -23,15 +32,15 @@ use leptos::*;
 // in the specific places it is actually used, not the whole app.
 
 #[component]
-fn Option2(cx: Scope) -> impl IntoView {
+fn Option2() -> impl IntoView {
     // here we create a signal in the root that can be consumed
     // anywhere in the app.
-    let (count, set_count) = create_signal(cx, 0);
+    let (count, set_count) = signal(0);
     // we'll pass the setter to specific components,
     // but provide the count itself to the whole app via context
-    provide_context(cx, count);
+    provide_context(count);
 
-    view! { cx,
+    view! {
         <h1>"Option 2: Passing Signals"</h1>
         // SetterButton is allowed to modify the count
         <SetterButton set_count/>
@@ -46,8 +55,8 @@ fn Option2(cx: Scope) -> impl IntoView {
 
 /// A button that increments our global counter.
 #[component]
-fn SetterButton(cx: Scope, set_count: WriteSignal<u32>) -> impl IntoView {
-    view! { cx,
+fn SetterButton(set_count: WriteSignal<u32>) -> impl IntoView {
+    view! {
         <div class="provider red">
             <button on:click=move |_| set_count.update(|count| *count += 1)>
                 "Increment Global Count"
@@ -58,14 +67,14 @@ fn SetterButton(cx: Scope, set_count: WriteSignal<u32>) -> impl IntoView {
 
 /// A component that does some "fancy" math with the global count
 #[component]
-fn FancyMath(cx: Scope) -> impl IntoView {
+fn FancyMath() -> impl IntoView {
     // here we consume the global count signal with `use_context`
-    let count = use_context::<ReadSignal<u32>>(cx)
+    let count = use_context::<ReadSignal<u32>>()
         // we know we just provided this in the parent component
         .expect("there to be a `count` signal provided");
-    let is_even = move || count() & 1 == 0;
+    let is_even = move || count.get() & 1 == 0;
 
-    view! { cx,
+    view! {
         <div class="consumer blue">
             "The number "
             <strong>{count}</strong>
@@ -81,17 +90,17 @@ fn FancyMath(cx: Scope) -> impl IntoView {
 
 /// A component that shows a list of items generated from the global count.
 #[component]
-fn ListItems(cx: Scope) -> impl IntoView {
+fn ListItems() -> impl IntoView {
     // again, consume the global count signal with `use_context`
-    let count = use_context::<ReadSignal<u32>>(cx).expect("there to be a `count` signal provided");
+    let count = use_context::<ReadSignal<u32>>().expect("there to be a `count` signal provided");
 
     let squares = move || {
-        (0..count())
-            .map(|n| view! { cx, <li>{n}<sup>"2"</sup> " is " {n * n}</li> })
-            .collect_view(cx)
+        (0..count.get())
+            .map(|n| view! { <li>{n}<sup>"2"</sup> " is " {n * n}</li> })
+            .collect_view()
     };
 
-    view! { cx,
+    view! {
         <div class="consumer green">
             <ul>{squares}</ul>
         </div>
@@ -102,107 +111,359 @@ fn ListItems(cx: Scope) -> impl IntoView {
 //
 // You can use this approach to build a single global data structure
 // that holds the state for your whole app, and then access it by
-// taking fine-grained slices using `create_slice` or `create_memo`,
-// so that changing one part of the state doesn't cause parts of your
-// app that depend on other parts of the state to change.
+// taking fine-grained slices of it, so that changing one part of the
+// state doesn't cause parts of your app that depend on other parts
+// of the state to change.
+//
+// A slice can be written by hand with `create_slice`, a getter and a setter
+// closure for every field. `#[derive(Store)]` from `reactive_stores` writes
+// them for us instead: a `Store<GlobalState>` gets one method per field,
+// which returns a "lens" into that field, and that works for nested
+// structs and `Vec`s too. A new field needs no extra code at all.
+//
+// Because the whole state is one value, it is also easy to keep copies of it:
+// `StateHistory` (in history.rs) keeps a snapshot from before every change,
+// which is all undo and redo need.
 
-#[derive(Default, Clone, Debug)]
+#[derive(Store, Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+// a state saved before a field was added still loads, the new field gets its default
+#[serde(default)]
 struct GlobalState {
     count: u32,
     name: String,
+    settings: Settings,
+    tags: Vec<String>,
+}
+
+#[derive(Store, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct Settings {
+    /// How much "Increment Global Count" adds.
+    step: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self { step: 1 }
+    }
 }
 
 #[component]
-fn Option3(cx: Scope) -> impl IntoView {
-    // we'll provide a single signal that holds the whole state
-    // each component will be responsible for creating its own "lens" into it
-    let state = create_rw_signal(cx, GlobalState::default());
-    provide_context(cx, state);
-
-    view! { cx,
-        <h1>"Option 3: Passing Signals"</h1>
-        <div class="red consumer" style="width: 100%">
-            <h2>"Current Global State"</h2>
-            <pre>
-                {move || {
-                    format!("{:#?}", state.get())
-                }}
-            </pre>
-        </div>
-        <div style="display: flex">
-            <GlobalStateCounter/>
-            <GlobalStateInput/>
+fn Option3() -> impl IntoView {
+    // we'll provide a single store that holds the whole state
+    // each component will be responsible for taking its own "lens" into it
+    // the state is kept in local storage and shared with the other open tabs, see sync.rs
+    let state = Store::new(sync::load().unwrap_or_default());
+    provide_context(state);
+    // the history is `Copy` like the store, so it goes through context the same way
+    let history = StateHistory::new(state);
+    provide_context(history);
+    sync::persist_and_sync(state, history);
+
+    // Ctrl+Z and Ctrl+Shift+Z (Cmd on a Mac) anywhere inside this part of the page
+    let on_keydown = move |ev: KeyboardEvent| {
+        if !(ev.ctrl_key() || ev.meta_key()) || !ev.key().eq_ignore_ascii_case("z") {
+            return;
+        }
+        // the input would otherwise undo its own text as well
+        ev.prevent_default();
+        if ev.shift_key() {
+            history.redo();
+        } else {
+            history.undo();
+        }
+    };
+
+    view! {
+        <div on:keydown=on_keydown>
+            <h1>"Option 3: Passing Signals"</h1>
+            <div class="provider">
+                <button disabled=move || !history.can_undo() on:click=move |_| history.undo()>
+                    "Undo"
+                </button>
+                <button disabled=move || !history.can_redo() on:click=move |_| history.redo()>
+                    "Redo"
+                </button>
+            </div>
+            <div class="red consumer" style="width: 100%">
+                <h2>"Current Global State"</h2>
+                <pre>
+                    {move || {
+                        format!("{:#?}", state.get())
+                    }}
+                </pre>
+            </div>
+            <div style="display: flex">
+                <GlobalStateCounter/>
+                <GlobalStateInput/>
+                <GlobalStateTags/>
+            </div>
         </div>
     }
 }
 
 /// A component that updates the count in the global state.
 #[component]
-fn GlobalStateCounter(cx: Scope) -> impl IntoView {
-    let state = use_context::<RwSignal<GlobalState>>(cx).expect("state to have been provided");
-
-    // `create_slice` lets us create a "lens" into the data
-    let (count, set_count) = create_slice(
-        cx,
-        // we take a slice *from* `state`
-        state,
-        // our getter returns a "slice" of the data
-        |state| state.count,
-        // our setter describes how to mutate that slice, given a new value
-        |state, n| state.count = n,
-    );
-
-    view! { cx,
+fn GlobalStateCounter() -> impl IntoView {
+    let state = use_context::<Store<GlobalState>>().expect("state to have been provided");
+    let history =
+        use_context::<StateHistory<GlobalState>>().expect("history to have been provided");
+
+    // `count()` is the "lens" into the count, it reads and writes like a signal
+    let count = state.count();
+    // a nested struct has lenses into its own fields
+    let step = state.settings().step();
+
+    view! {
         <div class="consumer blue">
             <button
                 on:click=move |_| {
-                    set_count(count() + 1);
+                    history.record();
+                    *count.write() += step.get();
                 }
             >
                 "Increment Global Count"
             </button>
+            <label>
+                " by "
+                <input
+                    type="number"
+                    min="1"
+                    prop:value=move || step.get().to_string()
+                    on:input=move |ev| {
+                        if let Ok(n) = event_target_value(&ev).parse() {
+                            history.record_typing("step");
+                            step.set(n);
+                        }
+                    }
+                />
+            </label>
             <br/>
-            <span>"Count is: " {count}</span>
+            <span>"Count is: " {move || count.get()}</span>
         </div>
     }
 }
 
-/// A component that updates the count in the global state.
+/// A component that updates the name in the global state.
 #[component]
-fn GlobalStateInput(cx: Scope) -> impl IntoView {
-    let state = use_context::<RwSignal<GlobalState>>(cx).expect("state to have been provided");
+fn GlobalStateInput() -> impl IntoView {
+    let state = use_context::<Store<GlobalState>>().expect("state to have been provided");
+    let history =
+        use_context::<StateHistory<GlobalState>>().expect("history to have been provided");
 
-    // this slice is completely independent of the `count` slice
-    // that we created in the other component
+    // this lens is completely independent of the `count` lens
+    // that we took in the other component
     // neither of them will cause the other to rerun
-    let (name, set_name) = create_slice(
-        cx,
-        // we take a slice *from* `state`
-        state,
-        // our getter returns a "slice" of the data
-        |state| state.name.clone(),
-        // our setter describes how to mutate that slice, given a new value
-        |state, n| state.name = n,
-    );
-
-    view! { cx,
+    let name = state.name();
+
+    view! {
         <div class="consumer green">
             <input
                 type="text"
-                prop:value=name
+                prop:value=move || name.get()
                 on:input=move |ev| {
-                    set_name(event_target_value(&ev));
+                    // a word typed in one go is a single undo step
+                    history.record_typing("name");
+                    name.set(event_target_value(&ev));
                 }
             />
             <br/>
-            <span>"Name is: " {name}</span>
+            <span>"Name is: " {move || name.get()}</span>
         </div>
     }
 }
+
+/// A component that adds tags to a list in the global state.
+#[component]
+fn GlobalStateTags() -> impl IntoView {
+    let state = use_context::<Store<GlobalState>>().expect("state to have been provided");
+    let history =
+        use_context::<StateHistory<GlobalState>>().expect("history to have been provided");
+
+    let tags = state.tags();
+    // what is typed in the box is not part of the global state until it is added
+    let (new_tag, set_new_tag) = signal(String::new());
+
+    let add_tag = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        let tag = new_tag.get().trim().to_owned();
+        if !tag.is_empty() {
+            history.record();
+            tags.write().push(tag);
+            set_new_tag.set(String::new());
+        }
+    };
+
+    view! {
+        <div class="consumer red">
+            <form on:submit=add_tag>
+                <input
+                    type="text"
+                    placeholder="New tag"
+                    prop:value=new_tag
+                    on:input=move |ev| set_new_tag.set(event_target_value(&ev))
+                />
+                <button type="submit">"Add Tag"</button>
+            </form>
+            <ul>
+                // a `Vec` field hands out a lens for every item in it
+                {move || {
+                    tags.iter_unkeyed()
+                        .map(|tag| view! { <li>{move || tag.get()}</li> })
+                        .collect_view()
+                }}
+            </ul>
+        </div>
+    }
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <Option2/>
+        <Option3/>
+    }
+}
+
 // This `main` function is the entry point into the app
 // It just mounts our component to the <body>
 // Because we defined it as `fn App`, we can now use it in a
 // template as <App/>
 fn main() {
-    leptos::mount_to_body(|cx| view! { cx, <Option2/><Option3/> })
+    leptos::mount::mount_to_body(|| view! { <App/> })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::testing::render_to_string;
+
+    #[test]
+    fn app_snapshot() {
+        let html = render_to_string(|| view! { <App/> });
+
+        insta::assert_snapshot!(html);
+    }
+}
+
+// These tests need a browser, see the README for how to run them.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use super::*;
+    use components::testing::{click, mount, press, text, type_into};
+    use leptos::task::tick;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// `Option3` starts from what the last test left in local storage otherwise.
+    fn clear_saved_state() {
+        window().local_storage().unwrap().unwrap().clear().unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn global_state_counter_increments_the_global_count() {
+        let state = Store::new(GlobalState::default());
+        let root = mount(move || {
+            provide_context(state);
+            provide_context(StateHistory::new(state));
+            view! { <GlobalStateCounter/> }
+        });
+        assert_eq!(text(&root, "span"), "Count is: 0");
+
+        click(&root, "Increment Global Count");
+        click(&root, "Increment Global Count");
+        tick().await;
+
+        assert_eq!(text(&root, "span"), "Count is: 2");
+        assert_eq!(state.get_untracked().count, 2);
+        // the slice only ever touches the count
+        assert_eq!(state.get_untracked().name, "");
+    }
+
+    #[wasm_bindgen_test]
+    async fn undo_and_redo_buttons_step_through_the_counts() {
+        clear_saved_state();
+        let root = mount(|| view! { <Option3/> });
+
+        click(&root, "Increment Global Count");
+        click(&root, "Increment Global Count");
+        tick().await;
+        click(&root, "Undo");
+        tick().await;
+        assert_eq!(text(&root, ".blue span"), "Count is: 1");
+
+        click(&root, "Redo");
+        tick().await;
+        assert_eq!(text(&root, ".blue span"), "Count is: 2");
+    }
+
+    #[wasm_bindgen_test]
+    async fn shortcuts_undo_a_typed_word_in_one_step() {
+        clear_saved_state();
+        let root = mount(|| view! { <Option3/> });
+
+        type_into(&root, ".green input", "L");
+        type_into(&root, ".green input", "Le");
+        type_into(&root, ".green input", "Leo");
+        tick().await;
+        press(&root, ".green input", "Ctrl+z");
+        tick().await;
+        assert_eq!(text(&root, ".green span"), "Name is: ");
+
+        press(&root, ".green input", "Ctrl+Shift+Z");
+        tick().await;
+        assert_eq!(text(&root, ".green span"), "Name is: Leo");
+    }
+
+    #[wasm_bindgen_test]
+    async fn state_is_saved_and_taken_over_from_other_tabs() {
+        clear_saved_state();
+        let root = mount(|| view! { <Option3/> });
+
+        click(&root, "Increment Global Count");
+        tick().await;
+        let saved = window()
+            .local_storage()
+            .unwrap()
+            .unwrap()
+            .get_item("global_state");
+        assert_eq!(
+            saved.unwrap().as_deref(),
+            Some(r#"{"count":1,"name":"","settings":{"step":1},"tags":[]}"#)
+        );
+
+        // what the browser fires when another tab saves
+        let init = web_sys::StorageEventInit::new();
+        init.set_key(Some("global_state"));
+        init.set_new_value(Some(r#"{"count":7,"name":"Ada"}"#));
+        let event = web_sys::StorageEvent::new_with_event_init_dict("storage", &init).unwrap();
+        window().dispatch_event(&event).unwrap();
+        tick().await;
+
+        assert_eq!(text(&root, ".blue span"), "Count is: 7");
+        assert_eq!(text(&root, ".green span"), "Name is: Ada");
+
+        // undo takes back what the other tab did, not what this tab did before it
+        click(&root, "Undo");
+        tick().await;
+        assert_eq!(text(&root, ".blue span"), "Count is: 1");
+        assert_eq!(text(&root, ".green span"), "Name is: ");
+    }
+
+    #[wasm_bindgen_test]
+    async fn lenses_reach_nested_fields_and_lists() {
+        clear_saved_state();
+        let root = mount(|| view! { <Option3/> });
+
+        type_into(&root, ".blue input", "5");
+        click(&root, "Increment Global Count");
+        tick().await;
+        assert_eq!(text(&root, ".blue span"), "Count is: 5");
+
+        type_into(&root, "form input", "rust");
+        click(&root, "Add Tag");
+        tick().await;
+        assert_eq!(text(&root, "li"), "rust");
+    }
 }
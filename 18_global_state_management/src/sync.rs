@@ -0,0 +1,81 @@
+//! Keeps the global state of Option 3 in local storage, so it survives a reload,
+//! and shares it with every other tab of the lesson that is open.
+//!
+//! Whenever one tab writes the state to local storage, the browser fires a `storage`
+//! event in all the *other* tabs of the same site, which then take the new state over.
+//! Taking it over is an undo step like any other, so undo goes back to what this tab had.
+
+use components::storage;
+use leptos::{ev, prelude::*};
+use reactive_stores::Store;
+
+use crate::{history::StateHistory, GlobalState};
+
+const STORAGE_KEY: &str = "global_state";
+
+/// The state the last tab saved, if any.
+pub fn load() -> Option<GlobalState> {
+    storage::load(STORAGE_KEY)
+}
+
+/// Saves every change of `state`, and takes over the changes that other tabs save.
+/// `history` gets an undo step for every change taken over.
+pub fn persist_and_sync(state: Store<GlobalState>, history: StateHistory<GlobalState>) {
+    Effect::new(move |_| storage::save(STORAGE_KEY, &*state.read()));
+
+    if cfg!(target_arch = "wasm32") {
+        let handle = window_event_listener(ev::storage, move |ev| {
+            if ev.key().as_deref() != Some(STORAGE_KEY) {
+                return;
+            }
+            // no new value means the key was removed, e.g. by clearing the site data
+            let theirs = ev
+                .new_value()
+                .and_then(|json| storage::parse(&json))
+                .unwrap_or_default();
+            // setting the same value again would only rerun everything that reads the state
+            if state.with_untracked(|ours| *ours != theirs) {
+                history.record();
+                state.set(theirs);
+            }
+        });
+        on_cleanup(move || handle.remove());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_state_round_trips() {
+        let state = GlobalState {
+            count: 3,
+            name: "Leo".to_owned(),
+            settings: crate::Settings { step: 2 },
+            tags: vec!["a".to_owned(), "b".to_owned()],
+        };
+
+        let json = serde_json::to_string(&state).unwrap();
+
+        assert_eq!(storage::parse::<GlobalState>(&json), Some(state));
+    }
+
+    #[test]
+    fn fields_missing_from_an_older_save_get_their_default() {
+        let state = storage::parse::<GlobalState>(r#"{"count":3,"name":"Leo"}"#).unwrap();
+
+        assert_eq!(state.count, 3);
+        assert_eq!(state.settings.step, 1);
+        assert!(state.tags.is_empty());
+    }
+
+    #[test]
+    fn unreadable_state_is_ignored() {
+        assert_eq!(
+            storage::parse::<GlobalState>("{\"count\": \"three\"}"),
+            None
+        );
+        assert_eq!(storage::parse::<GlobalState>(""), None);
+    }
+}
@@ -0,0 +1,13 @@
+use leptos::config::LeptosOptions;
+
+/// Renders every route on the server, try a deep link like `/about_me/special`.
+/// The wasm bundle that hydrates the page is expected in `pkg/`, see the README for how to build it.
+#[tokio::main]
+async fn main() {
+    let options = LeptosOptions::builder()
+        .output_name(env!("CARGO_PKG_NAME"))
+        .site_root(env!("CARGO_MANIFEST_DIR"))
+        .build();
+
+    ssr_server::serve(options, router_definition::shell).await;
+}
@@ -0,0 +1,80 @@
+//! Errors that a route shows instead of its page, see [`RouteError`] and [`RouteErrors`].
+
+use std::{fmt, sync::Arc};
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Why a route can't show its page. Loaders return it, pages [`raise`] it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RouteError {
+    NotFound,
+    Forbidden,
+    /// The loader of the route failed, with what went wrong.
+    LoaderFailed(String),
+}
+
+impl RouteError {
+    /// The HTTP status code that the server answers with.
+    pub fn status(&self) -> u16 {
+        match self {
+            RouteError::NotFound => 404,
+            RouteError::Forbidden => 403,
+            RouteError::LoaderFailed(_) => 500,
+        }
+    }
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteError::NotFound => f.write_str("PAGE NOT FOUND"),
+            RouteError::Forbidden => f.write_str("YOU CAN'T SEE THIS PAGE"),
+            RouteError::LoaderFailed(reason) => write!(f, "THE PAGE FAILED TO LOAD: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for RouteError {}
+
+/// The error route that [`RouteErrors`] provides.
+#[derive(Clone)]
+struct ErrorRoute(Arc<dyn Fn(RouteError) -> AnyView + Send + Sync>);
+
+/// Shows the error route in place of the page.
+/// On the server, the response gets the status code of the error.
+pub fn raise(error: RouteError) -> impl IntoView {
+    #[cfg(feature = "ssr")]
+    if let Some(status) = use_context::<ssr_server::ResponseStatus>() {
+        status.set(error.status());
+    }
+    let ErrorRoute(view) = use_context().expect("the routes to be inside `<RouteErrors/>`");
+    view(error)
+}
+
+/// Provides `view` as the error route, for the routes inside that [`raise`] a [`RouteError`].
+#[component]
+pub fn RouteErrors<F, V>(
+    /// Shows the error, in place of the page that raised it.
+    view: F,
+    children: Children,
+) -> impl IntoView
+where
+    F: Fn(RouteError) -> V + Send + Sync + 'static,
+    V: IntoView + 'static,
+{
+    provide_context(ErrorRoute(Arc::new(move |error| view(error).into_any())));
+    children()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_error_has_its_status() {
+        assert_eq!(RouteError::NotFound.status(), 404);
+        assert_eq!(RouteError::Forbidden.status(), 403);
+        assert_eq!(RouteError::LoaderFailed("down".to_owned()).status(), 500);
+    }
+}
@@ -0,0 +1,134 @@
+//! Pages that only a logged in user gets to see, see [`GuardedRoute`].
+
+use components::{use_session, LoginForm};
+use leptos::prelude::*;
+use leptos_router::{
+    components::ProtectedRoute,
+    hooks::{use_location, use_navigate, use_query_map},
+    location::Url,
+    MatchNestedRoutes, NavigateOptions, PossibleRouteMatch,
+};
+use typed_routes::Routable;
+
+use crate::{
+    error::{raise, RouteError},
+    AppRoute,
+};
+
+/// A `<Route/>` that redirects to the login page unless somebody is logged in to the provided
+/// session. The login page is given the requested URL as `?return=`, and goes back to it once the
+/// user has logged in. Logging out while on the page redirects as well.
+#[component(transparent)]
+pub fn GuardedRoute<Segments, ViewFn, V>(
+    /// The path of the route, made with `path!()` like for `<Route/>`.
+    path: Segments,
+    /// The page that is guarded.
+    view: ViewFn,
+) -> impl MatchNestedRoutes + Clone
+where
+    Segments: PossibleRouteMatch + Clone + Send + std::fmt::Debug + 'static,
+    ViewFn: Fn() -> V + Send + Clone + 'static,
+    V: IntoView + 'static,
+{
+    let session = use_session();
+    let location = use_location();
+    let redirect_path = move || {
+        // `search` comes without its `?`
+        let mut requested = location.pathname.get_untracked();
+        let search = location.search.get_untracked();
+        if !search.is_empty() {
+            requested = format!("{requested}?{search}");
+        }
+        format!(
+            "{}?return={}",
+            AppRoute::Login.to_path(),
+            Url::escape(&requested)
+        )
+    };
+
+    // `view!` wraps the route in a `View`, `<Routes/>` wants the route itself
+    view! {
+        <ProtectedRoute
+            path
+            view
+            condition=move || Some(session.is_logged_in())
+            redirect_path
+        />
+    }
+    .into_inner()
+}
+
+/// The page to go back to after the login. Only paths on this site are followed,
+/// anything else, like `//evil.example`, goes home.
+///
+/// Browsers read a `\` in a URL as a `/` and drop tabs and newlines, so `/\evil.example` and
+/// `/\t/evil.example` are `//evil.example` too. A path with either of them goes home as well.
+fn return_path(requested: Option<String>) -> String {
+    requested
+        .filter(|path| {
+            path.starts_with('/')
+                && !path.starts_with("//")
+                && !path.contains(|c: char| c == '\\' || c.is_control())
+        })
+        .unwrap_or_else(|| AppRoute::Home.to_path())
+}
+
+#[component]
+pub fn Login() -> impl IntoView {
+    let query = use_query_map();
+    let navigate = use_navigate();
+    let on_login = move |_| {
+        let path = return_path(query.read_untracked().get("return"));
+        navigate(&path, NavigateOptions::default());
+    };
+
+    view! {
+        <h1>"LOG IN"</h1>
+        <p>"Try alice / wonderland or bob / builder."</p>
+        <LoginForm on_login/>
+    }
+}
+
+#[component]
+pub fn Account() -> impl IntoView {
+    let session = use_session();
+
+    view! {
+        <h1 id="account_page">"HELLO " {move || session.user()}</h1>
+        <button on:click=move |_| session.logout()>"Log out"</button>
+    }
+}
+
+/// Only for alice, everybody else who is logged in is forbidden.
+#[component]
+pub fn Admin() -> impl IntoView {
+    let session = use_session();
+
+    move || {
+        if session.user().as_deref() == Some("alice") {
+            view! { <h1 id="admin_page">"HELLO ADMIN"</h1> }.into_any()
+        } else {
+            raise(RouteError::Forbidden).into_any()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_returns_to_this_site() {
+        assert_eq!(
+            return_path(Some("/account?tab=1".to_owned())),
+            "/account?tab=1"
+        );
+        assert_eq!(return_path(Some("//evil.example".to_owned())), "/");
+        assert_eq!(return_path(Some("https://evil.example".to_owned())), "/");
+        // what `?return=/%5Cevil.example` decodes to
+        assert_eq!(return_path(Some("/\\evil.example".to_owned())), "/");
+        assert_eq!(return_path(Some("/\t/evil.example".to_owned())), "/");
+        assert_eq!(return_path(Some("/\n/evil.example".to_owned())), "/");
+        assert_eq!(return_path(None), "/");
+    }
+}
@@ -0,0 +1,229 @@
+use components::Session;
+use leptos::prelude::*;
+use leptos_router::components::Router;
+use typed_routes::{Routable, TypedRoutes};
+
+mod error;
+mod guard;
+mod loader;
+mod profiles;
+
+use error::{raise, RouteError, RouteErrors};
+use guard::{Account, Admin, GuardedRoute, Login};
+use loader::{use_route_data, LoaderRoute, SetLoading};
+use profiles::{load_profile, Profile};
+
+/// The whole page, as the server sends it.
+/// Only the `<body>` is hydrated, the `<head>` carries the styles and the scripts that load the wasm bundle.
+#[cfg(feature = "ssr")]
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    use leptos::hydration::HydrationScripts;
+
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <title>"Leptos Learn"</title>
+                <meta charset="utf-8"/>
+                <style>{include_str!("../style.css")}</style>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <RouterDemo/>
+            </body>
+        </html>
+    }
+}
+
+/// Entry point of the wasm bundle when the page was rendered on the server.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    leptos::mount::hydrate_body(RouterDemo);
+}
+
+/// Routing behavior is provided by the `<Router/>` component.
+/// This should usually be somewhere near the root of your application.
+/// You shouldn't try to use multiple `<Router/>`'s in your app!
+///
+/// The `<Routers/>` component is where you define all the routes to which a user can navigate in your application.
+/// Each possible route is defined by a `<Route/>` component. You can see the syntax below.
+///
+/// You should place the `<Routes/>` component at the location within your app where you want routes to be rendered.
+/// Everything outside `<Routes/>` will be present on every page, so you can leave things like a navigation bar
+/// or menu outside the `<Routes/>`.
+///
+/// Individual routes are defined by providing children to <Routes/> with the <Route/> component.
+/// <Route/> takes a path and a view. When the current location matches path, the view will be created and displayed.
+/// Here the routes are the variants of `AppRoute`, `#[derive(Routable)]` writes the `<Route/>`s for us.
+/// `<Routes/>` also takes a `fallback`, which is shown when none of the routes match.
+/// The path can include:
+///     - a static path ( /about_me ),
+///     - dynamic, named parameters beginning with a colon ( /about_me/:id ),
+///     - and/or a wildcard beginning with an asterisk ( /*any ),
+///
+/// `<GuardedRoute/>` is a `<Route/>` that sends you to the login page first, see the `guard` module.
+/// `<LoaderRoute/>` hands the matched `AppRoute` to its loader first, which loads the data of the page, see the `loader` module.
+/// `set_is_routing` tells us when the router is waiting for that data, the old page stays until then.
+/// A route that can't show its page raises a `RouteError`, and shows the `ErrorPage` that `<RouteErrors/>` provides.
+#[component]
+pub fn RouterDemo() -> impl IntoView {
+    // the guarded routes ask it who is logged in
+    Session::provide();
+    let (is_routing, set_is_routing) = signal(false);
+    let (is_loading, set_is_loading) = signal(false);
+    provide_context(SetLoading(set_is_loading));
+    let pending = move || is_routing.get() || is_loading.get();
+
+    // a typo in a route is a compile error, not a link to the 404 page
+    let links = [
+        ("HOME", AppRoute::Home),
+        ("ABOUT ME", AppRoute::About),
+        (
+            "ABOUT with :id",
+            AppRoute::AboutSpecial {
+                id: "special".to_owned(),
+            },
+        ),
+        (
+            "ABOUT another :id",
+            AppRoute::AboutSpecial {
+                id: "regular".to_owned(),
+            },
+        ),
+        ("ACCOUNT", AppRoute::Account),
+        ("ADMIN", AppRoute::Admin),
+        (
+            "BROKEN",
+            AppRoute::AboutSpecial {
+                id: "broken".to_owned(),
+            },
+        ),
+        (
+            "UNDEFINED",
+            AppRoute::NotFound {
+                any: "jbg_dec".to_owned(),
+            },
+        ),
+    ];
+
+    view! {
+        <Router set_is_routing>
+            <nav>
+                <ul class="navigation_list">
+                    {links
+                        .into_iter()
+                        .map(|(label, route)| view! {
+                            <li>
+                                <a href=route.to_path()>{label}</a>
+                            </li>
+                        })
+                        .collect_view()}
+                    <li class="pending">{move || pending().then_some("Loading...")}</li>
+                </ul>
+            </nav>
+            <main>
+                <RouteErrors view=|error| view! { <ErrorPage error/> }>
+                    <TypedRoutes routes=AppRoute::routes fallback=|| raise(RouteError::NotFound)/>
+                </RouteErrors>
+            </main>
+        </Router>
+    }
+}
+
+/// Every page of the app. The path of a route is in its `#[route]`, its params are its fields.
+/// `component` picks another route component than `<Route/>`, with the props that follow it.
+#[derive(Routable, Debug, Clone, PartialEq)]
+pub enum AppRoute {
+    #[route("/", view = Home)]
+    Home,
+    #[route("/about_me", view = About)]
+    About,
+    #[route("/about_me/:id", view = AboutSpecial, component = LoaderRoute, loader = load_profile)]
+    AboutSpecial { id: String },
+    #[route("/login", view = Login)]
+    Login,
+    #[route("/account", view = Account, component = GuardedRoute)]
+    Account,
+    #[route("/admin", view = Admin, component = GuardedRoute)]
+    Admin,
+    #[route("/*any", view = || raise(RouteError::NotFound))]
+    NotFound { any: String },
+}
+
+#[component]
+fn Home() -> impl IntoView {
+    view! { <h1 id="home_page">"THIS IS MY HOME PAGE"</h1> }
+}
+
+#[component]
+fn About() -> impl IntoView {
+    view! { <h1 id="about_page">"ABOUT ME GENERIC"</h1> }
+}
+
+/// The profile that the `:id` names, loaded by the route before the page is shown.
+#[component]
+fn AboutSpecial() -> impl IntoView {
+    let profile = use_route_data::<Profile>();
+
+    move || {
+        Suspend::new(async move {
+            match profile.await {
+                Ok(profile) => view! {
+                    <h1 id="about_special">{profile.bio}</h1>
+                    <p>"This is " {profile.name} ", id " {profile.id}</p>
+                }
+                .into_any(),
+                Err(error) => raise(error).into_any(),
+            }
+        })
+    }
+}
+
+/// The error route, what a page that raised a `RouteError` shows instead.
+#[component]
+fn ErrorPage(error: RouteError) -> impl IntoView {
+    view! { <h1 class="route_error">{error.status()} " - " {error.to_string()}</h1> }
+}
+
+// The pages are rendered by the server, the tests turn the `ssr` feature on, see Cargo.toml.
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn app_snapshot() {
+        let page = ssr_server::render("/about_me/special", || view! { <RouterDemo/> }).await;
+
+        insta::assert_snapshot!(page.html);
+    }
+
+    async fn render(url: &str) -> ssr_server::Page {
+        ssr_server::render(url, || view! { <RouterDemo/> }).await
+    }
+
+    #[tokio::test]
+    async fn unknown_pages_are_not_found() {
+        let page = render("/nope").await;
+
+        assert_eq!(page.status.as_u16(), 404);
+        assert_eq!(page.redirect, None);
+    }
+
+    #[tokio::test]
+    async fn failed_loaders_are_server_errors() {
+        let page = render("/about_me/broken").await;
+
+        assert_eq!(page.status.as_u16(), 500);
+        assert_eq!(page.redirect, None);
+    }
+
+    #[tokio::test]
+    async fn guarded_pages_redirect_to_the_login() {
+        let page = render("/admin").await;
+
+        // the page itself went fine, the response turns the redirect into a 303
+        assert_eq!(page.status.as_u16(), 200);
+        assert_eq!(page.redirect.as_deref(), Some("/login?return=%2Fadmin"));
+    }
+}
@@ -0,0 +1,107 @@
+//! Routes that load their data before they are shown, see [`LoaderRoute`].
+
+use std::{future::Future, mem::discriminant};
+
+use leptos::{prelude::*, reactive::wrappers::write::SignalSetter};
+use leptos_router::{components::Route, MatchNestedRoutes, PossibleRouteMatch};
+use serde::{de::DeserializeOwned, Serialize};
+use typed_routes::{use_route, Routable};
+
+use crate::error::RouteError;
+
+/// A `<Route/>` with a `loader` that takes the variant of the route enum `R` that leads here,
+/// with the params in its fields, and loads what the page shows. The page reads it with [`use_route_data`].
+///
+/// The data is loaded inside a `<Transition/>`. While it loads the router keeps showing the old page,
+/// and when only the params change, the page keeps showing the old data. The [`SetLoading`] in context
+/// is told whether a load is running, so the app can show that something is happening.
+#[component(transparent)]
+pub fn LoaderRoute<Segments, R, Fut, T, ViewFn, V>(
+    /// The path of the route, made with `path!()` like for `<Route/>`.
+    path: Segments,
+    /// Loads the data of the page, or fails with the error that is shown instead.
+    loader: fn(R) -> Fut,
+    /// The page, it gets the data through [`use_route_data`].
+    view: ViewFn,
+) -> impl MatchNestedRoutes + Clone
+where
+    Segments: PossibleRouteMatch + Clone + Send + std::fmt::Debug + 'static,
+    R: Routable + Clone + PartialEq + Send + Sync + 'static,
+    Fut: Future<Output = Result<T, RouteError>> + Send + 'static,
+    T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    ViewFn: Fn() -> V + Clone + Send + Sync + 'static,
+    V: IntoView + 'static,
+{
+    let set_pending = use_context::<SetLoading>().map(|SetLoading(set)| SignalSetter::from(set));
+    let view = move || {
+        let route = use_this_route::<R>();
+        // params that don't parse don't lead anywhere
+        let data = Resource::new(
+            move || route.get(),
+            move |route| {
+                let load = route.map(loader);
+                async move {
+                    match load {
+                        Some(load) => load.await,
+                        None => Err(RouteError::NotFound),
+                    }
+                }
+            },
+        );
+        provide_context(RouteData(data));
+
+        let view = view.clone();
+        view! {
+            <Transition
+                fallback=|| view! { <p class="pending">"Loading..."</p> }
+                set_pending=set_pending.unwrap_or_default()
+            >
+                {view()}
+            </Transition>
+        }
+    };
+
+    // `view!` wraps the route in a `View`, `<Routes/>` wants the route itself
+    view! { <Route path view/> }.into_inner()
+}
+
+/// The current route, as long as it is the variant that the page was opened with.
+/// On the way to another page the route changes before the page is gone, its data shouldn't reload then.
+fn use_this_route<R>() -> Memo<Option<R>>
+where
+    R: Routable + Clone + PartialEq + Send + Sync + 'static,
+{
+    let route = use_route::<R>();
+    Memo::new(move |this: Option<&Option<R>>| {
+        let current = route.get();
+        match (this, &current) {
+            (Some(Some(this)), Some(current)) if discriminant(this) != discriminant(current) => {
+                Some(this.clone())
+            }
+            _ => current,
+        }
+    })
+}
+
+/// Set to `true` while a [`LoaderRoute`] loads the data of its page. Provide it to show that somewhere.
+#[derive(Clone, Copy)]
+pub struct SetLoading(pub WriteSignal<bool>);
+
+/// The data that the [`LoaderRoute`] loads, in context for its page.
+struct RouteData<T: Send + Sync + 'static>(Resource<Result<T, RouteError>>);
+
+impl<T: Send + Sync + 'static> Clone for RouteData<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for RouteData<T> {}
+
+/// The data that the loader of the current [`LoaderRoute`] loads, read it inside a `Suspend`.
+/// [`raise`](crate::error::raise) the error to show the error route.
+pub fn use_route_data<T: Send + Sync + 'static>() -> Resource<Result<T, RouteError>> {
+    use_context::<RouteData<T>>()
+        .expect("the page to be the view of a `<LoaderRoute/>`")
+        .0
+}
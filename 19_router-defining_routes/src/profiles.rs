@@ -0,0 +1,58 @@
+//! The profiles behind `/about_me/:id`.
+//!
+//! They are compiled into the app, [`load_profile`] only waits a bit to act like an API would.
+
+use std::time::Duration;
+
+use components::sleep;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RouteError, AppRoute};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+    pub bio: String,
+}
+
+/// `(id, name, bio)`
+const PROFILES: &[(&str, &str, &str)] = &[
+    ("special", "Special", "I AM SPECIAL, LOOK AT ME!"),
+    ("regular", "Regular", "Nothing to see here, move along."),
+];
+
+/// The profile that `/about_me/:id` names.
+pub async fn load_profile(route: AppRoute) -> Result<Profile, RouteError> {
+    let AppRoute::AboutSpecial { id } = route else {
+        return Err(RouteError::NotFound);
+    };
+    sleep(Duration::from_millis(500)).await;
+    match id.as_str() {
+        // an API has its bad days too
+        "broken" => Err(RouteError::LoaderFailed("the profile is broken".to_owned())),
+        id => find(id).ok_or(RouteError::NotFound),
+    }
+}
+
+fn find(id: &str) -> Option<Profile> {
+    PROFILES
+        .iter()
+        .find(|(profile, ..)| *profile == id)
+        .map(|(id, name, bio)| Profile {
+            id: id.to_string(),
+            name: name.to_string(),
+            bio: bio.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_profiles_by_id() {
+        assert_eq!(find("special").unwrap().name, "Special");
+        assert!(find("jbg_dec").is_none());
+    }
+}
@@ -0,0 +1,86 @@
+//! The data of the contact app.
+//!
+//! It comes from contacts.json, which is compiled into the app. The loaders are `async` anyway,
+//! so the components load them through resources just like they would from a real API.
+
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    pub phone: String,
+}
+
+/// One message of the conversation with a contact.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    /// Whether we sent it, or the contact did.
+    pub from_me: bool,
+    pub text: String,
+}
+
+/// A contact as it is stored in contacts.json, with the conversation.
+#[derive(Deserialize)]
+struct Record {
+    #[serde(flatten)]
+    contact: Contact,
+    messages: Vec<Message>,
+}
+
+static RECORDS: LazyLock<Vec<Record>> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("../contacts.json")).expect("contacts.json to be valid")
+});
+
+/// The contacts whose name contains `term`, ignoring case. An empty term matches everyone.
+pub async fn search_contacts(term: String) -> Vec<Contact> {
+    search(&RECORDS, &term)
+}
+
+pub async fn get_contact(id: String) -> Option<Contact> {
+    find(&RECORDS, &id).map(|record| record.contact.clone())
+}
+
+/// The conversation with the contact `id`, `None` if there is no such contact.
+pub async fn get_messages(id: String) -> Option<Vec<Message>> {
+    find(&RECORDS, &id).map(|record| record.messages.clone())
+}
+
+fn search(records: &[Record], term: &str) -> Vec<Contact> {
+    let term = term.trim().to_lowercase();
+    records
+        .iter()
+        .filter(|record| record.contact.name.to_lowercase().contains(&term))
+        .map(|record| record.contact.clone())
+        .collect()
+}
+
+fn find<'a>(records: &'a [Record], id: &str) -> Option<&'a Record> {
+    records.iter().find(|record| record.contact.id == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(contacts: Vec<Contact>) -> Vec<String> {
+        contacts.into_iter().map(|contact| contact.name).collect()
+    }
+
+    #[test]
+    fn searches_names_ignoring_case() {
+        assert_eq!(names(search(&RECORDS, " AL ")), ["Alice"]);
+        assert_eq!(names(search(&RECORDS, "e")), ["Alice", "Steve"]);
+        assert_eq!(search(&RECORDS, "").len(), RECORDS.len());
+        assert!(search(&RECORDS, "zed").is_empty());
+    }
+
+    #[test]
+    fn finds_contacts_by_id() {
+        assert_eq!(find(&RECORDS, "bob").unwrap().messages.len(), 2);
+        assert!(find(&RECORDS, "mallory").is_none());
+    }
+}
@@ -0,0 +1,344 @@
+use contacts::{get_contact, get_messages, search_contacts, Contact};
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Form, Outlet, Router, A},
+    hooks::{use_location, use_query_map},
+};
+use typed_routes::{use_route, Routable, TypedRoutes};
+
+mod contacts;
+
+/// The whole page, as the server sends it.
+/// Only the `<body>` is hydrated, the `<head>` carries the styles and the scripts that load the wasm bundle.
+#[cfg(feature = "ssr")]
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    use leptos::hydration::HydrationScripts;
+
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <title>"Leptos Learn"</title>
+                <meta charset="utf-8"/>
+                <style>{include_str!("../style.css")}</style>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+
+/// Entry point of the wasm bundle when the page was rendered on the server.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    leptos::mount::hydrate_body(App);
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    let contacts = AppRoute::Contacts {
+        contact: ContactsRoute::Index,
+    };
+
+    view! {
+        <Router>
+            <h1>"Contact App"</h1>
+            // this <nav> will show on every route,
+            // because it's outside the <Routes/>
+            // note: we can just use normal <a> tags
+            // and the router will use client-side navigation
+            <nav>
+                <h2>"Navigation"</h2>
+                <a href=AppRoute::Home.to_path()>"Home"</a>
+                <a href=contacts.to_path()>"Contacts"</a>
+            </nav>
+            <main>
+                <TypedRoutes routes=AppRoute::routes fallback=|| "Not found."/>
+            </main>
+        </Router>
+    }
+}
+
+/// The routes of the app, `#[derive(Routable)]` turns them into the `<Routes/>` tree.
+/// A variant with a `#[nested]` field is a `<ParentRoute/>`, the routes of that field are its children.
+#[derive(Routable, Debug, Clone, PartialEq)]
+pub enum AppRoute {
+    // / just has an un-nested "Home"
+    #[route("/", view = || view! { <h3>"Home"</h3> })]
+    Home,
+    // /contacts has nested routes
+    #[route("/contacts", view = ContactList)]
+    Contacts {
+        #[nested]
+        contact: ContactsRoute,
+    },
+}
+
+/// The routes inside `/contacts`.
+#[derive(Routable, Debug, Clone, PartialEq)]
+pub enum ContactsRoute {
+    // /contacts/:id also has nested routes
+    #[route(":id", view = ContactInfo)]
+    Contact {
+        id: String,
+        #[nested]
+        tab: ContactTab,
+    },
+    // if no id specified, fall back
+    #[route("", view = NoContact)]
+    Index,
+}
+
+/// The tabs of a contact, inside `/contacts/:id`.
+#[derive(Routable, Debug, Clone, PartialEq)]
+pub enum ContactTab {
+    #[route("conversations", view = Conversations)]
+    Conversations,
+    // if no conversations specified, fall back
+    #[route("", view = ContactDetails)]
+    Details,
+}
+
+/// Links that keep the query string of the current URL, so following one keeps the search.
+fn use_link() -> impl Fn(AppRoute) -> String + Copy {
+    let search = use_location().search;
+    move |route| {
+        let path = route.to_path();
+        search.with(|search| {
+            if search.is_empty() {
+                path
+            } else {
+                format!("{path}?{search}")
+            }
+        })
+    }
+}
+
+/// The `:id` of the open contact, parsed from the URL by `AppRoute`.
+fn use_contact_id() -> Memo<String> {
+    let route = use_route::<AppRoute>();
+    Memo::new(move |previous: Option<&String>| match route.get() {
+        Some(AppRoute::Contacts {
+            contact: ContactsRoute::Contact { id, .. },
+        }) => id,
+        // the contact stays until its page is gone, on the way to another route
+        _ => previous.cloned().unwrap_or_default(),
+    })
+}
+
+/// The link to a tab of the contact `id`.
+fn contact_tab(id: String, tab: ContactTab) -> AppRoute {
+    AppRoute::Contacts {
+        contact: ContactsRoute::Contact { id, tab },
+    }
+}
+
+#[component]
+fn ContactList() -> impl IntoView {
+    // the search term lives in the query string, so a search can be bookmarked and shared:
+    // `/contacts?q=al`, or `/contacts/alice/conversations?q=al` with a contact open
+    let query = use_query_map();
+    let term = move || query.read().get("q").unwrap_or_default();
+    let link = use_link();
+    // the search goes to the page it was made on, so an open contact stays open
+    let pathname = use_location().pathname;
+    // reloads whenever the term changes
+    let contacts = Resource::new(term, search_contacts);
+
+    view! {
+        <div class="contact-list">
+            // here's our contact list component itself
+            <div class="contact-list-contacts">
+                <h3>"Contacts"</h3>
+                // a GET form puts its inputs into the query string, `name="q"` becomes `?q=`
+                <Form method="GET" action=move || pathname.get()>
+                    <input type="search" name="q" placeholder="Search" value=term/>
+                </Form>
+                <Transition fallback=|| view! { <p>"Loading contacts..."</p> }>
+                    {move || Suspend::new(async move {
+                        let contacts = contacts.await;
+                        if contacts.is_empty() {
+                            return view! { <p>"No contact matches."</p> }.into_any();
+                        }
+                        contacts
+                            .into_iter()
+                            .map(|contact| {
+                                let href = move || link(contact_tab(contact.id.clone(), ContactTab::Details));
+                                view! { <A href=href>{contact.name}</A> }
+                            })
+                            .collect_view()
+                            .into_any()
+                    })}
+                </Transition>
+            </div>
+            // <Outlet/> will show the nested child route
+            // we can position this outlet wherever we want
+            // within the layout
+            <Outlet/>
+        </div>
+    }
+}
+
+#[component]
+fn ContactInfo() -> impl IntoView {
+    // the :id param, already parsed by the route enum
+    let id = use_contact_id();
+    let link = use_link();
+
+    // loads the contact whenever the :id changes
+    let contact = Resource::new(move || id.get(), get_contact);
+    // the tabs are nested routes, they get the contact from here instead of loading it again
+    provide_context(contact);
+
+    view! {
+        <div class="contact-info">
+            <Transition fallback=|| view! { <h4>"Loading..."</h4> }>
+                {move || Suspend::new(async move {
+                    match contact.await {
+                        Some(contact) => view! { <h4>{contact.name}</h4> }.into_any(),
+                        None => view! { <h4>"User not found."</h4> }.into_any(),
+                    }
+                })}
+            </Transition>
+            <div class="tabs">
+                <A href=move || link(contact_tab(id.get(), ContactTab::Details)) exact=true>
+                    "Contact Info"
+                </A>
+                <A href=move || link(contact_tab(id.get(), ContactTab::Conversations))>
+                    "Conversations"
+                </A>
+            </div>
+
+            // <Outlet/> here is the tabs that are nested
+            // underneath the /contacts/:id route
+            <Outlet/>
+        </div>
+    }
+}
+
+/// What `/contacts` shows before a contact is picked.
+#[component]
+fn NoContact() -> impl IntoView {
+    view! { <div class="select-user">"Select a user to view contact info."</div> }
+}
+
+/// The "Contact Info" tab.
+#[component]
+fn ContactDetails() -> impl IntoView {
+    let contact = use_context::<Resource<Option<Contact>>>().expect("the contact to be loading");
+
+    view! {
+        <div class="tab">
+            <Transition fallback=|| view! { <p>"Loading..."</p> }>
+                {move || Suspend::new(async move {
+                    contact.await.map(|contact| view! {
+                        <dl>
+                            <dt>"Email"</dt>
+                            <dd>{contact.email}</dd>
+                            <dt>"Phone"</dt>
+                            <dd>{contact.phone}</dd>
+                        </dl>
+                    })
+                })}
+            </Transition>
+        </div>
+    }
+}
+
+/// The "Conversations" tab, the messages between us and the contact.
+#[component]
+fn Conversations() -> impl IntoView {
+    let id = use_contact_id();
+    let messages = Resource::new(move || id.get(), get_messages);
+
+    view! {
+        <div class="tab">
+            <Transition fallback=|| view! { <p>"Loading messages..."</p> }>
+                {move || Suspend::new(async move {
+                    match messages.await {
+                        Some(messages) if messages.is_empty() => {
+                            view! { <p>"No messages yet."</p> }.into_any()
+                        }
+                        Some(messages) => view! {
+                            <ul class="messages">
+                                {messages
+                                    .into_iter()
+                                    .map(|message| {
+                                        let class = if message.from_me { "from-me" } else { "from-them" };
+                                        view! { <li class=class>{message.text}</li> }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                        .into_any(),
+                        None => ().into_any(),
+                    }
+                })}
+            </Transition>
+        </div>
+    }
+}
+
+// The snapshot is rendered by the server, the tests turn the `ssr` feature on, see Cargo.toml.
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn app_snapshot() {
+        let page =
+            ssr_server::render("/contacts/alice/conversations?q=al", || view! { <App/> }).await;
+
+        insta::assert_snapshot!(page.html);
+    }
+}
+
+// These tests need a browser, see the README for how to run them.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use std::time::Duration;
+
+    use super::*;
+    use components::{
+        sleep,
+        testing::{mount, submit, text, type_into},
+    };
+    use leptos::wasm_bindgen::JsValue;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Gives the router and the resources a moment to catch up.
+    async fn settle() {
+        sleep(Duration::from_millis(50)).await;
+    }
+
+    fn location() -> String {
+        let location = window().location();
+        location.pathname().unwrap() + &location.search().unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    async fn searching_keeps_the_open_contact() {
+        let deep_link = "/contacts/alice/conversations";
+        window()
+            .history()
+            .unwrap()
+            .push_state_with_url(&JsValue::NULL, "", Some(deep_link))
+            .unwrap();
+        let root = mount(|| view! { <App/> });
+        settle().await;
+        let contact = text(&root, ".contact-info h4");
+        assert_ne!(contact, "");
+
+        type_into(&root, "input[name=q]", "al");
+        submit(&root, "form");
+        settle().await;
+
+        assert_eq!(location(), format!("{deep_link}?q=al"));
+        assert_eq!(text(&root, ".contact-info h4"), contact);
+    }
+}
@@ -1,108 +1,6 @@
-use leptos::*;
-use leptos_router::*;
-
-#[component]
-fn App(cx: Scope) -> impl IntoView {
-    view! { cx,
-        <Router>
-            <h1>"Contact App"</h1>
-            // this <nav> will show on every route,
-            // because it's outside the <Routes/>
-            // note: we can just use normal <a> tags
-            // and the router will use client-side navigation
-            <nav>
-                <h2>"Navigation"</h2>
-                <a href="/">"Home"</a>
-                <a href="/contacts">"Contacts"</a>
-            </nav>
-            <main>
-                <Routes>
-                    // / just has an un-nested "Home"
-                    <Route path="/" view=|cx| view! { cx,
-                        <h3>"Home"</h3>
-                    }/>
-                    // /contacts has nested routes
-                    <Route
-                        path="/contacts"
-                        view=|cx| view! { cx, <ContactList/> }
-                    >
-                        // /contacts/:id also has nested routes
-                        <Route path=":id" view=|cx| view! { cx,
-                            <ContactInfo/>
-                        }>
-                            <Route path="conversations" view=|cx| view! { cx,
-                                <div class="tab">
-                                    "(Conversations)"
-                                </div>
-                            }/>
-                            // if no conversations specified, fall back
-                            <Route path="" view=|cx| view! { cx,
-                                <div class="tab">
-                                    "(Contact Info)"
-                                </div>
-                            }/>
-                        </Route>
-                        // if no id specified, fall back
-                        <Route path="" view=|cx| view! { cx,
-                            <div class="select-user">
-                                "Select a user to view contact info."
-                            </div>
-                        }/>
-                    </Route>
-                </Routes>
-            </main>
-        </Router>
-    }
-}
-
-#[component]
-fn ContactList(cx: Scope) -> impl IntoView {
-    view! { cx,
-        <div class="contact-list">
-            // here's our contact list component itself
-            <div class="contact-list-contacts">
-                <h3>"Contacts"</h3>
-                <A href="alice">"Alice"</A>
-                <A href="bob">"Bob"</A>
-                <A href="steve">"Steve"</A>
-            </div>
-            // <Outlet/> will show the nested child route
-            // we can position this outlet wherever we want
-            // within the layout
-            <Outlet/>
-        </div>
-    }
-}
-
-#[component]
-fn ContactInfo(cx: Scope) -> impl IntoView {
-    // we can access the :id param reactively with `use_params_map`
-    let params = use_params_map(cx);
-    let id = move || params.with(|params| params.get("id").cloned().unwrap_or_default());
-
-    // imagine we're loading data from an API here
-    let name = move || match id().as_str() {
-        "alice" => "Alice",
-        "bob" => "Bob",
-        "steve" => "Steve",
-        _ => "User not found.",
-    };
-
-    view! { cx,
-        <div class="contact-info">
-            <h4>{name}</h4>
-            <div class="tabs">
-                <A href="" exact=true>"Contact Info"</A>
-                <A href="conversations">"Conversations"</A>
-            </div>
-
-            // <Outlet/> here is the tabs that are nested
-            // underneath the /contacts/:id route
-            <Outlet/>
-        </div>
-    }
-}
+use leptos::prelude::*;
+use nested_routing::App;
 
 fn main() {
-    leptos::mount_to_body(|cx| view! { cx, <App/> })
+    leptos::mount::mount_to_body(|| view! { <App/> })
 }
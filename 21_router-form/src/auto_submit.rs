@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use leptos::{
+    ev::Event,
+    prelude::*,
+    wasm_bindgen::JsCast,
+    web_sys::{Element, HtmlFormElement},
+};
+use leptos_router::components::Form;
+
+/// A `GET` [`Form`] that submits itself once the user stops typing for `debounce`.
+///
+/// Every `input` event inside the form restarts the timer, so typing a word is one submit and not
+/// one per letter. By default the submit replaces the current history entry instead of pushing
+/// a new one, the back button then skips over all the intermediate queries.
+#[component]
+pub fn AutoSubmitForm(
+    /// Where the form navigates to, the current route if left empty.
+    #[prop(optional)]
+    action: &'static str,
+    /// How long the inputs have to stay unchanged before the form is submitted.
+    #[prop(default = Duration::from_millis(300))]
+    debounce: Duration,
+    /// Whether a submit replaces the current history entry, instead of pushing a new one.
+    #[prop(default = true)]
+    replace: bool,
+    children: Children,
+) -> impl IntoView {
+    let pending = StoredValue::new(None::<TimeoutHandle>);
+    let cancel = move || {
+        if let Some(handle) = pending.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    };
+    on_cleanup(cancel);
+
+    let schedule = move |ev: Event| {
+        let Some(form) = event_target::<Element>(&ev)
+            .closest("form")
+            .ok()
+            .flatten()
+            .and_then(|form| form.dyn_into::<HtmlFormElement>().ok())
+        else {
+            return;
+        };
+        cancel();
+        let handle = set_timeout_with_handle(
+            move || {
+                pending.set_value(None);
+                // like a click on a submit button, so `<Form/>` turns it into a client-side navigation
+                _ = form.request_submit();
+            },
+            debounce,
+        );
+        pending.set_value(handle.ok());
+    };
+
+    view! {
+        <Form method="GET" action=action replace=replace noscroll=true>
+            // `input` bubbles up from every input and select inside the form
+            <div style="display: contents" on:input=schedule>
+                {children()}
+            </div>
+        </Form>
+    }
+}
@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use auto_submit::AutoSubmitForm;
+use leptos::prelude::*;
+use leptos_router::components::{Form, Router};
+use query::{use_form_query, Choice};
+use typed_routes::{Routable, TypedRoutes};
+
+mod auto_submit;
+mod query;
+
+/// The whole page, as the server sends it.
+/// Only the `<body>` is hydrated, the `<head>` carries the styles and the scripts that load the wasm bundle.
+#[cfg(feature = "ssr")]
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    use leptos::hydration::HydrationScripts;
+
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <title>"Leptos Playground"</title>
+                <meta charset="utf-8"/>
+                <style>{include_str!("../style.css")}</style>
+                <HydrationScripts options/>
+            </head>
+            <body>
+                <App/>
+            </body>
+        </html>
+    }
+}
+
+/// Entry point of the wasm bundle when the page was rendered on the server.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    leptos::mount::hydrate_body(App);
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <Router>
+            <h1><code>"<Form/>"</code></h1>
+            <main>
+                <TypedRoutes routes=AppRoute::routes fallback=|| "Not found."/>
+            </main>
+        </Router>
+    }
+}
+
+/// The one page of the app, the query string carries the rest.
+#[derive(Routable, Debug, Clone, PartialEq)]
+pub enum AppRoute {
+    #[route("", view = FormExample)]
+    FormExample,
+}
+
+#[component]
+pub fn FormExample() -> impl IntoView {
+    // reactive access to URL query, parsed into a `FormQuery` (see query.rs)
+    let (query, set_query) = use_form_query();
+    // the inputs show the query as long as it parses, and start out empty if it doesn't
+    let current = move || query.get().unwrap_or_default();
+    let name = move || current().name.unwrap_or_default();
+    let number = move || {
+        current()
+            .number
+            .0
+            .map(|n| n.to_string())
+            .unwrap_or_default()
+    };
+    let select = move || current().select.0;
+
+    // the other way around: change the struct, and it is written back to the URL
+    let increment = {
+        let set_query = set_query.clone();
+        move |_| {
+            let mut query = query.get_untracked().unwrap_or_default();
+            query.number.0 = Some(query.number.0.unwrap_or_default() + 1);
+            set_query(query);
+        }
+    };
+    let clear = move |_| set_query(Default::default());
+
+    view! {
+        // read out the URL query strings
+        // a value that doesn't parse, like `?number=abc`, ends up in the fallback
+        <ErrorBoundary fallback=|errors| view! {
+            <div class="error">
+                <p>"The query string doesn't fit the form: "</p>
+                <ul>
+                    {move || errors.get()
+                        .into_iter()
+                        .map(|(_, e)| view! { <li>{e.to_string()}</li> })
+                        .collect::<Vec<_>>()
+                    }
+                </ul>
+            </div>
+        }>
+            {move || query.get().map(|query| view! {
+                <table>
+                    <tr>
+                        <td><code>"name"</code></td>
+                        <td>{query.name}</td>
+                    </tr>
+                    <tr>
+                        <td><code>"number"</code></td>
+                        <td>{query.number.0}</td>
+                    </tr>
+                    <tr>
+                        <td><code>"select"</code></td>
+                        <td>{query.select.0.map(|choice| choice.to_string())}</td>
+                    </tr>
+                </table>
+            })}
+        </ErrorBoundary>
+        <button on:click=increment>"number + 1"</button>
+        <button on:click=clear>"Clear"</button>
+        // <Form/> will navigate whenever submitted
+        <h2>"Manual Submission"</h2>
+        <Form method="GET" action="">
+            // input names determine query string key
+            <input type="text" name="name" value=name/>
+            <input type="number" name="number" value=number/>
+            <select name="select">
+                // `selected` will set which starts as selected
+                <option selected=move || select() == Some(Choice::A)>
+                    "A"
+                </option>
+                <option selected=move || select() == Some(Choice::B)>
+                    "B"
+                </option>
+                <option selected=move || select() == Some(Choice::C)>
+                    "C"
+                </option>
+            </select>
+            // submitting should cause a client-side
+            // navigation, not a full reload
+            <input type="submit"/>
+        </Form>
+        // This form submits itself 300ms after the last input, see auto_submit.rs
+        // the submits replace the history entry, so the back button doesn't step through every letter
+        <h2>"Automatic Submission"</h2>
+        <AutoSubmitForm debounce=Duration::from_millis(300)>
+            <input type="text" name="name" value=name/>
+            <input type="number" name="number" value=number/>
+            <select name="select">
+                <option selected=move || select() == Some(Choice::A)>
+                    "A"
+                </option>
+                <option selected=move || select() == Some(Choice::B)>
+                    "B"
+                </option>
+                <option selected=move || select() == Some(Choice::C)>
+                    "C"
+                </option>
+            </select>
+            // submitting should cause a client-side
+            // navigation, not a full reload
+            <input type="submit"/>
+        </AutoSubmitForm>
+    }
+}
+
+// The snapshot is rendered by the server, the tests turn the `ssr` feature on, see Cargo.toml.
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn app_snapshot() {
+        let page = ssr_server::render("/", || view! { <App/> }).await;
+
+        insta::assert_snapshot!(page.html);
+    }
+}
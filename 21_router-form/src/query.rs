@@ -0,0 +1,268 @@
+//! The query string of [`FormExample`](crate::FormExample) as a typed struct.
+//!
+//! `#[derive(Params)]` writes the parsing: every field is parsed from the query value of the same
+//! name, through the field type's [`IntoParam`]. [`use_form_query`] reads the struct from the URL
+//! and hands out a setter that writes one back.
+
+use std::{error::Error, fmt, str::FromStr};
+
+use leptos::prelude::*;
+use leptos_router::{
+    hooks::{use_location, use_navigate, use_query},
+    location::Url,
+    params::{IntoParam, Params, ParamsError},
+    NavigateOptions,
+};
+
+#[derive(Params, Debug, Clone, Default, PartialEq)]
+pub struct FormQuery {
+    pub name: Option<String>,
+    pub number: OrEmpty<i64>,
+    pub select: OrEmpty<Choice>,
+}
+
+impl FormQuery {
+    /// The query string that parses back into `self`, with the leading `?`.
+    /// Empty fields are left out.
+    pub fn to_query_string(&self) -> String {
+        let pairs = [
+            ("name", self.name.clone()),
+            ("number", self.number.0.map(|n| n.to_string())),
+            ("select", self.select.0.map(|choice| choice.to_string())),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some(format!("{key}={}", Url::escape(&value?))))
+        .collect::<Vec<_>>();
+
+        if pairs.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", pairs.join("&"))
+        }
+    }
+}
+
+/// The options of the `<select>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Choice {
+    A,
+    B,
+    C,
+}
+
+impl Choice {
+    pub const ALL: [Choice; 3] = [Choice::A, Choice::B, Choice::C];
+}
+
+impl fmt::Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl FromStr for Choice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Choice::ALL
+            .into_iter()
+            .find(|choice| choice.to_string() == s)
+            .ok_or_else(|| "pick A, B or C".to_owned())
+    }
+}
+
+/// A value that may be missing from the query string, or be empty:
+/// an input that was left blank is still submitted, as `number=`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrEmpty<T>(pub Option<T>);
+
+impl<T> Default for OrEmpty<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> IntoParam for OrEmpty<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fn into_param(value: Option<&str>, name: &str) -> Result<Self, ParamsError> {
+        match value {
+            None | Some("") => Ok(Self(None)),
+            Some(value) => value.parse().map(|value| Self(Some(value))).map_err(|err| {
+                ParamsError::Params(std::sync::Arc::new(InvalidValue {
+                    key: name.to_owned(),
+                    value: value.to_owned(),
+                    reason: err.to_string(),
+                }))
+            }),
+        }
+    }
+}
+
+/// A query value that doesn't parse into the type of its field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidValue {
+    pub key: String,
+    pub value: String,
+    pub reason: String,
+}
+
+impl fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` can't be \"{}\": {}",
+            self.key, self.value, self.reason
+        )
+    }
+}
+
+impl Error for InvalidValue {}
+
+impl From<ParamsError> for InvalidValue {
+    fn from(error: ParamsError) -> Self {
+        // every field of `FormQuery` fails with an `InvalidValue`, the rest is only for completeness
+        match &error {
+            ParamsError::Params(inner) => inner.downcast_ref::<InvalidValue>().cloned(),
+            ParamsError::MissingParam(_) => None,
+        }
+        .unwrap_or_else(|| InvalidValue {
+            key: "query".to_owned(),
+            value: String::new(),
+            reason: error.to_string(),
+        })
+    }
+}
+
+/// The [`FormQuery`] in the URL, and a setter that navigates to the URL of another one.
+/// Each set is a history entry of its own, like a submit of the form.
+pub fn use_form_query() -> (
+    Memo<Result<FormQuery, InvalidValue>>,
+    impl Fn(FormQuery) + Clone,
+) {
+    let query = use_query::<FormQuery>();
+    let query = Memo::new(move |_| query.get().map_err(InvalidValue::from));
+
+    let navigate = use_navigate();
+    let pathname = use_location().pathname;
+    let set_query = move |query: FormQuery| {
+        let url = format!("{}{}", pathname.get_untracked(), query.to_query_string());
+        navigate(&url, NavigateOptions::default());
+    };
+
+    (query, set_query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(value: Option<&str>) -> Result<OrEmpty<i64>, InvalidValue> {
+        OrEmpty::into_param(value, "number").map_err(InvalidValue::from)
+    }
+
+    #[test]
+    fn parses_a_submitted_value() {
+        assert_eq!(number(Some("42")), Ok(OrEmpty(Some(42))));
+        assert_eq!(
+            OrEmpty::<Choice>::into_param(Some("B"), "select").unwrap(),
+            OrEmpty(Some(Choice::B))
+        );
+    }
+
+    #[test]
+    fn blank_and_missing_values_are_empty() {
+        assert_eq!(number(Some("")), Ok(OrEmpty(None)));
+        assert_eq!(number(None), Ok(OrEmpty(None)));
+    }
+
+    #[test]
+    fn names_the_value_that_does_not_parse() {
+        let error = number(Some("12a")).unwrap_err();
+        assert_eq!(error.key, "number");
+        assert_eq!(error.value, "12a");
+
+        let error = OrEmpty::<Choice>::into_param(Some("D"), "select").unwrap_err();
+        assert_eq!(
+            InvalidValue::from(error).to_string(),
+            "`select` can't be \"D\": pick A, B or C"
+        );
+    }
+
+    // the router unescapes the query values through the browser unless it is built for the server,
+    // so `from_map` and `to_query_string` are tested with the `ssr` feature the tests turn on
+    #[cfg(feature = "ssr")]
+    mod query_string {
+        use leptos_router::params::ParamsMap;
+
+        use super::*;
+
+        #[test]
+        fn parses_the_whole_query() {
+            let map = ParamsMap::from_iter([("name", "Ann"), ("number", "7"), ("select", "C")]);
+            assert_eq!(
+                FormQuery::from_map(&map).map_err(InvalidValue::from),
+                Ok(FormQuery {
+                    name: Some("Ann".to_owned()),
+                    number: OrEmpty(Some(7)),
+                    select: OrEmpty(Some(Choice::C)),
+                })
+            );
+
+            // nothing submitted yet
+            assert_eq!(
+                FormQuery::from_map(&ParamsMap::new()).map_err(InvalidValue::from),
+                Ok(FormQuery::default())
+            );
+        }
+
+        #[test]
+        fn refuses_a_number_that_is_not_one() {
+            let map = ParamsMap::from_iter([("name", "Ann"), ("number", "seven")]);
+
+            let error = InvalidValue::from(FormQuery::from_map(&map).unwrap_err());
+            assert_eq!(error.key, "number");
+            assert_eq!(error.value, "seven");
+        }
+
+        /// The query string as the router hands it to `from_map`.
+        fn parse_query(query: &str) -> ParamsMap {
+            query
+                .trim_start_matches('?')
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    (key.to_owned(), Url::unescape(value))
+                })
+                .collect()
+        }
+
+        #[test]
+        fn round_trips_through_the_query_string() {
+            let queries = [
+                FormQuery::default(),
+                FormQuery {
+                    name: Some("Ann & Bo = 100%".to_owned()),
+                    number: OrEmpty(Some(-3)),
+                    select: OrEmpty(Some(Choice::A)),
+                },
+                FormQuery {
+                    name: None,
+                    number: OrEmpty(None),
+                    select: OrEmpty(Some(Choice::B)),
+                },
+            ];
+
+            for query in queries {
+                let map = parse_query(&query.to_query_string());
+                assert_eq!(
+                    FormQuery::from_map(&map).map_err(InvalidValue::from),
+                    Ok(query)
+                );
+            }
+        }
+    }
+}
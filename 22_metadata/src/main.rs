@@ -1,14 +1,14 @@
-use leptos::*;
+use leptos::prelude::*;
 use leptos_meta::*;
 
 // ALL of this can obviously be made to be reactive!
 // There is also a <Script/>, <Html/> and <Body/> components, the details are in the Leptos Book.
 
-/// You can also use this to import some custom CSS from another file at compile time with: 
+/// You can also use this to import some custom CSS from another file at compile time with:
 /// `<Style> { include_str!("my_route.css") } </Style>`
 #[component]
-fn Styling(cx: Scope) -> impl IntoView {
-    view! { cx,  
+fn Styling() -> impl IntoView {
+    view! {
         <Style>
             "body {
                 color: white;
@@ -20,8 +20,8 @@ fn Styling(cx: Scope) -> impl IntoView {
 
 /// You can import links with a Link component, there is also a Stylesheet component that is just shortened <link rel="stylesheet">.
 #[component]
-fn AddLinks(cx: Scope) -> impl IntoView {
-    view! { cx,
+fn AddLinks() -> impl IntoView {
+    view! {
         <Link rel="preconnect" href="https://fonts.googleapis.com"/>
         <Link rel="preconnect" href="https://fonts.gstatic.com" crossorigin="anonymous"/>
         <Stylesheet
@@ -32,8 +32,8 @@ fn AddLinks(cx: Scope) -> impl IntoView {
 }
 
 #[component]
-fn ChMeta(cx: Scope,) -> impl IntoView {
-    view! {cx, 
+fn ChMeta() -> impl IntoView {
+    view! {
         <Meta name="description" content="Meta injection demo"/>
         <Meta name="author" content="Vid Kavšek"/>
     }
@@ -41,22 +41,25 @@ fn ChMeta(cx: Scope,) -> impl IntoView {
 
 /// The title of the webpage changes as you type and is formatted according to the 'formatter' in the App component.
 #[component]
-fn ChTitle(cx: Scope) -> impl IntoView {
-    let (title, set_title) = create_signal(cx, "Webpage".to_string());
-    let inp = move |ev| set_title(event_target_value(&ev));
+fn ChTitle() -> impl IntoView {
+    let (title, set_title) = signal("Webpage".to_string());
+    let inp = move |ev| set_title.set(event_target_value(&ev));
 
-    view! { cx,
-        <Title text=title/>
+    view! {
+        <Title text=move || title.get()/>
         <h2>"Change the title of this webpage"</h2>
         <input type="text" on:input=inp/>
     }
 }
 
 #[component]
-fn App(cx: Scope) -> impl IntoView {
-    let formatter = |text| format!("{} - Spletka", text);
+fn App() -> impl IntoView {
+    // the meta context has to be provided before any of the meta components are used
+    provide_meta_context();
 
-    view! { cx,
+    let formatter = |text: String| format!("{} - Spletka", text);
+
+    view! {
         <Styling/>
         <AddLinks/>
         <ChMeta/>
@@ -69,5 +72,33 @@ fn App(cx: Scope) -> impl IntoView {
 }
 
 fn main() {
-    mount_to_body(|cx| view! { cx, <App/> });
+    leptos::mount::mount_to_body(|| view! { <App/> });
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[test]
+    fn app_snapshot() {
+        let owner = Owner::new();
+        let html = owner.with(|| {
+            // on the server the meta components don't touch the document, they hand their tags
+            // to this context, which puts them into the <head> of the rendered page
+            let (meta, head) = ServerMetaContext::new();
+            provide_context(meta);
+            let body = view! { <App/> }.to_html();
+            let page = stream::iter([format!("<head></head><body>{body}</body>")]);
+
+            block_on(async {
+                head.inject_meta_context(page)
+                    .await
+                    .collect::<String>()
+                    .await
+            })
+        });
+
+        insta::assert_snapshot!(html);
+    }
 }
@@ -0,0 +1,165 @@
+//! Who is logged in, see [`Session`].
+//!
+//! There is no server to ask, the accounts live in a list compiled into the app and the session
+//! only lasts until the page is reloaded. That is enough for the lessons to guard their pages.
+
+use std::fmt;
+
+use leptos::{ev::SubmitEvent, prelude::*};
+
+use crate::form::{required, FieldError, FormState};
+
+/// The accounts that can log in, as `(name, password)`.
+const CREDENTIALS: &[(&str, &str)] = &[("alice", "wonderland"), ("bob", "builder")];
+
+/// Why [`Session::login`] refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginError {
+    UnknownUser,
+    WrongPassword,
+}
+
+impl fmt::Display for LoginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoginError::UnknownUser => f.write_str("there is no such user"),
+            LoginError::WrongPassword => f.write_str("the password is wrong"),
+        }
+    }
+}
+
+impl std::error::Error for LoginError {}
+
+fn check_credentials(name: &str, password: &str) -> Result<(), LoginError> {
+    match CREDENTIALS.iter().find(|(user, _)| *user == name) {
+        None => Err(LoginError::UnknownUser),
+        Some((_, expected)) if *expected != password => Err(LoginError::WrongPassword),
+        Some(_) => Ok(()),
+    }
+}
+
+/// The name of the logged in user, if there is one. `Copy`, so it goes through context,
+/// see [`Session::provide`] and [`use_session`].
+#[derive(Clone, Copy)]
+pub struct Session {
+    user: RwSignal<Option<String>>,
+}
+
+impl Session {
+    /// Creates a session that nobody is logged in to and provides it through context.
+    pub fn provide() -> Self {
+        let session = Self {
+            user: RwSignal::new(None),
+        };
+        provide_context(session);
+        session
+    }
+
+    pub fn user(self) -> Option<String> {
+        self.user.get()
+    }
+
+    pub fn is_logged_in(self) -> bool {
+        self.user.with(Option::is_some)
+    }
+
+    /// Logs `name` in if the password matches, whoever was logged in before is logged out.
+    pub fn login(self, name: &str, password: &str) -> Result<(), LoginError> {
+        check_credentials(name, password)?;
+        self.user.set(Some(name.to_owned()));
+        Ok(())
+    }
+
+    pub fn logout(self) {
+        self.user.set(None);
+    }
+}
+
+/// Grabs the session that was provided with [`Session::provide`].
+pub fn use_session() -> Session {
+    use_context::<Session>().expect("the session to have been provided")
+}
+
+/// Asks for a name and a password and logs in to the provided [`Session`].
+/// `on_login` gets the name once that worked, what happens next is up to the parent.
+#[component]
+pub fn LoginForm<F>(on_login: F) -> impl IntoView
+where
+    F: Fn(String) + 'static,
+{
+    let session = use_session();
+    let form = FormState::new();
+    let name = form.field::<String>("").validate(required());
+    let password = form.field::<String>("").validate(required());
+    let (error, set_error) = signal(None::<LoginError>);
+
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        if !form.submit() {
+            return;
+        }
+        match session.login(&name.text(), &password.text()) {
+            Ok(()) => {
+                set_error.set(None);
+                form.reset();
+                on_login(session.user.get_untracked().unwrap_or_default());
+            }
+            Err(err) => set_error.set(Some(err)),
+        }
+    };
+
+    view! {
+        <form class="login" on:submit=on_submit>
+            <input
+                type="text"
+                placeholder="Name"
+                prop:value=move || name.text()
+                on:input=move |ev| name.set_text(event_target_value(&ev))
+            />
+            <FieldError field=name/>
+            <input
+                type="password"
+                placeholder="Password"
+                prop:value=move || password.text()
+                on:input=move |ev| password.set_text(event_target_value(&ev))
+            />
+            <FieldError field=password/>
+            <input type="submit" value="Log in"/>
+            <p class="login-error">{move || error.get().map(|err| err.to_string())}</p>
+        </form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_the_credentials() {
+        assert_eq!(check_credentials("alice", "wonderland"), Ok(()));
+        assert_eq!(
+            check_credentials("alice", "builder"),
+            Err(LoginError::WrongPassword)
+        );
+        assert_eq!(
+            check_credentials("mallory", "wonderland"),
+            Err(LoginError::UnknownUser)
+        );
+    }
+
+    #[test]
+    fn logs_in_and_out() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let session = Session::provide();
+            assert!(session.login("bob", "wonderland").is_err());
+            assert!(!session.is_logged_in());
+
+            session.login("bob", "builder").unwrap();
+            assert_eq!(use_session().user(), Some("bob".to_owned()));
+
+            session.logout();
+            assert_eq!(session.user(), None);
+        });
+    }
+}
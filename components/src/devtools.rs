@@ -0,0 +1,426 @@
+//! A debug overlay for the reactive graph.
+//!
+//! Leptos doesn't hand out the nodes of its reactive graph, so tracing is opt-in: the lessons keep
+//! creating their signals, memos and effects as usual, and hand the function of the memo or effect
+//! they want to see through [`Devtools::trace_memo`] or [`Devtools::trace_effect`] first.
+//! Every run of a traced function is counted, and whatever it reads is noted down as the graph
+//! itself sees it, derived signals are only closures, so their reads count for whoever calls them.
+//! Sources are listed under the names given with [`Devtools::name`], or where they were created.
+//! A named source gets a row of its own as well, with the traced nodes that read it.
+//! [`DevtoolsPanel`] shows all of that as a table.
+
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, Mutex, OnceLock, Weak},
+};
+
+use leptos::{
+    prelude::*,
+    reactive::graph::{
+        AnySource, AnySubscriber, Observer, ReactiveNode, Subscriber, ToAnySource, WithObserver,
+    },
+};
+
+/// What a node of the reactive graph is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    Signal,
+    Memo,
+    Effect,
+}
+
+impl NodeKind {
+    fn label(self) -> &'static str {
+        match self {
+            NodeKind::Signal => "signal",
+            NodeKind::Memo => "memo",
+            NodeKind::Effect => "effect",
+        }
+    }
+}
+
+/// A traced or named node. The counters are signals of their own,
+/// so a run only updates its own row of the panel.
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    id: usize,
+    kind: NodeKind,
+    name: &'static str,
+    /// How often it ran, always 0 for a signal.
+    runs: RwSignal<usize>,
+    /// Its [`key`] as a source, a memo only gets one once it ran.
+    key: RwSignal<Option<u64>>,
+    /// What it read the last time it ran.
+    sources: RwSignal<Vec<Source>>,
+}
+
+/// Something a traced node read.
+#[derive(Debug, Clone, PartialEq)]
+struct Source {
+    key: u64,
+    /// Its name, or where it was created if it has none.
+    label: String,
+}
+
+/// Keeps track of the traced nodes and of the names of the sources. `Copy`, so it goes through context.
+#[derive(Clone, Copy)]
+pub struct Devtools {
+    nodes: RwSignal<Vec<Node>>,
+    /// The names of the sources, by [`key`].
+    names: StoredValue<HashMap<u64, &'static str>>,
+    next_id: StoredValue<usize>,
+}
+
+impl Devtools {
+    /// Creates the devtools and provides them through context.
+    pub fn provide() -> Self {
+        let devtools = Self {
+            nodes: RwSignal::new(Vec::new()),
+            names: StoredValue::new(HashMap::new()),
+            next_id: StoredValue::new(0),
+        };
+        provide_context(devtools);
+        devtools
+    }
+
+    /// Lists `source`, a signal or a memo, under `name`, with a row of its own
+    /// and wherever it is read by a traced node.
+    pub fn name(self, name: &'static str, source: impl ToAnySource) {
+        let node = self.register(NodeKind::Signal, name);
+        self.set_key(node, key(&source.to_any_source()));
+    }
+
+    /// Counts the runs of the function of a [`Memo`] and notes down what it reads.
+    /// Traced memos are listed under their `name` when other traced nodes read them.
+    pub fn trace_memo<T>(
+        self,
+        name: &'static str,
+        f: impl Fn(Option<&T>) -> T + Send + Sync + 'static,
+    ) -> impl Fn(Option<&T>) -> T + Send + Sync + 'static {
+        let node = self.register(NodeKind::Memo, name);
+        let recorder = OnceLock::new();
+        move |prev| self.run(node, &recorder, || f(prev))
+    }
+
+    /// Counts the runs of the function of an [`Effect`] and notes down what it reads.
+    pub fn trace_effect<T>(
+        self,
+        name: &'static str,
+        mut f: impl FnMut(Option<T>) -> T + 'static,
+    ) -> impl FnMut(Option<T>) -> T + 'static {
+        let node = self.register(NodeKind::Effect, name);
+        let recorder = OnceLock::new();
+        move |prev| self.run(node, &recorder, || f(prev))
+    }
+
+    /// The traced nodes that read the source `key` the last time they ran.
+    fn readers(self, key: u64) -> Vec<&'static str> {
+        self.nodes.with(|nodes| {
+            nodes
+                .iter()
+                .filter(|node| {
+                    node.sources
+                        .with(|sources| sources.iter().any(|source| source.key == key))
+                })
+                .map(|node| node.name)
+                .collect()
+        })
+    }
+
+    /// Names the source `key` after `node`.
+    fn set_key(self, node: Node, key: u64) {
+        node.key.set(Some(key));
+        self.names.update_value(|names| {
+            names.insert(key, node.name);
+        });
+    }
+
+    /// Adds a node that is removed again when the current owner is cleaned up.
+    fn register(self, kind: NodeKind, name: &'static str) -> Node {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        let node = Node {
+            id,
+            kind,
+            name,
+            runs: RwSignal::new(0),
+            key: RwSignal::new(None),
+            sources: RwSignal::new(Vec::new()),
+        };
+        self.nodes.update(|nodes| nodes.push(node));
+        on_cleanup(move || {
+            // the devtools may have been cleaned up first
+            if let Some(key) = node.key.try_get_untracked().flatten() {
+                _ = self.names.try_update_value(|names| names.remove(&key));
+            }
+            _ = self
+                .nodes
+                .try_update(|nodes| nodes.retain(|node| node.id != id));
+        });
+        node
+    }
+
+    /// Runs `f` with a [`Recorder`] standing in for the node that is running it.
+    fn run<T>(self, node: Node, recorder: &OnceLock<Arc<Recorder>>, f: impl FnOnce() -> T) -> T {
+        let Some(subscriber) = Observer::get() else {
+            // not run by the graph, nothing to note down
+            return f();
+        };
+        let recorder = recorder.get_or_init(|| {
+            // a memo is a source under the same key as the subscriber it is here
+            self.set_key(node, key(&subscriber));
+            Arc::new(Recorder {
+                node: subscriber.clone(),
+                sources: Mutex::new(Vec::new()),
+            })
+        });
+        recorder.sources.lock().unwrap().clear();
+        let value = recorder.to_any_subscriber().with_observer(f);
+
+        node.runs.update(|runs| *runs += 1);
+        let sources = self.names.with_value(|names| {
+            recorder
+                .sources
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|source| {
+                    let key = key(source);
+                    let label = match names.get(&key) {
+                        Some(name) => name.to_string(),
+                        None => source
+                            .defined_at()
+                            .map_or_else(|| "?".to_owned(), |at| format!("({at})")),
+                    };
+                    Source { key, label }
+                })
+                .collect::<Vec<_>>()
+        });
+        node.sources.maybe_update(|old| {
+            let changed = *old != sources;
+            if changed {
+                *old = sources;
+            }
+            changed
+        });
+        value
+    }
+}
+
+/// Sources and subscribers of the graph are told apart by an id that is only readable through `Hash`.
+fn key(node: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    node.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Stands in for the memo or effect that runs a traced function. It passes everything on
+/// to the real node, and notes down the sources on the way. It uses the id of the real node,
+/// so the sources treat the two as the same subscriber.
+struct Recorder {
+    node: AnySubscriber,
+    sources: Mutex<Vec<AnySource>>,
+}
+
+impl Recorder {
+    fn to_any_subscriber(self: &Arc<Self>) -> AnySubscriber {
+        let this: Weak<dyn Subscriber + Send + Sync> = Arc::downgrade(self) as _;
+        AnySubscriber(self.node.0, this)
+    }
+}
+
+impl Subscriber for Recorder {
+    fn add_source(&self, source: AnySource) {
+        let mut sources = self.sources.lock().unwrap();
+        if !sources.contains(&source) {
+            sources.push(source.clone());
+        }
+        drop(sources);
+        self.node.add_source(source);
+    }
+
+    fn clear_sources(&self, subscriber: &AnySubscriber) {
+        self.node.clear_sources(subscriber);
+    }
+}
+
+impl ReactiveNode for Recorder {
+    fn mark_dirty(&self) {
+        self.node.mark_dirty();
+    }
+
+    fn mark_check(&self) {
+        self.node.mark_check();
+    }
+
+    fn mark_subscribers_check(&self) {
+        self.node.mark_subscribers_check();
+    }
+
+    fn update_if_necessary(&self) -> bool {
+        self.node.update_if_necessary()
+    }
+}
+
+/// Lists the nodes that [`Devtools`] knows about, how often each of them ran,
+/// what it read the last time it ran and which traced nodes read it.
+#[component]
+pub fn DevtoolsPanel() -> impl IntoView {
+    let devtools = use_context::<Devtools>().expect("devtools to have been provided");
+
+    view! {
+        <aside class="devtools">
+            <h2>"Reactive graph"</h2>
+            <table>
+                <thead>
+                    <tr>
+                        <th>"Kind"</th>
+                        <th>"Name"</th>
+                        <th>"Runs"</th>
+                        <th>"Reads"</th>
+                        <th>"Read by"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    <For each=move || devtools.nodes.get() key=|node| node.id let:node>
+                        <tr class=node.kind.label()>
+                            <td>{node.kind.label()}</td>
+                            <td><code>{node.name}</code></td>
+                            <td>
+                                {move || match node.kind {
+                                    NodeKind::Signal => "-".to_owned(),
+                                    _ => node.runs.get().to_string(),
+                                }}
+                            </td>
+                            <td>
+                                {move || {
+                                    node.sources
+                                        .read()
+                                        .iter()
+                                        .map(|source| source.label.as_str())
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                }}
+                            </td>
+                            <td>
+                                {move || {
+                                    node.key.get().map(|key| devtools.readers(key).join(", "))
+                                }}
+                            </td>
+                        </tr>
+                    </For>
+                </tbody>
+            </table>
+        </aside>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(devtools: Devtools, name: &str) -> Node {
+        devtools
+            .nodes
+            .with_untracked(|nodes| *nodes.iter().find(|node| node.name == name).unwrap())
+    }
+
+    fn reads(devtools: Devtools, name: &str) -> Vec<String> {
+        node(devtools, name)
+            .sources
+            .with_untracked(|sources| sources.iter().map(|source| source.label.clone()).collect())
+    }
+
+    fn read_by(devtools: Devtools, name: &str) -> Vec<&'static str> {
+        let key = node(devtools, name).key.get_untracked().unwrap();
+        untrack(|| devtools.readers(key))
+    }
+
+    #[test]
+    fn notes_down_what_every_run_reads() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let devtools = Devtools::provide();
+            let (count, set_count) = signal(0);
+            let (use_count, set_use_count) = signal(true);
+            devtools.name("count", count);
+            devtools.name("use_count", use_count);
+            // a derived signal isn't a node, its reads count for the memo
+            let shown = move || use_count.get().then(|| count.get());
+            let doubled =
+                Memo::new(devtools.trace_memo("doubled", move |_| shown().unwrap_or_default() * 2));
+            let label = Memo::new(devtools.trace_memo("label", move |_| doubled.get().to_string()));
+
+            assert_eq!(label.get_untracked(), "0");
+            assert_eq!(reads(devtools, "label"), ["doubled"]);
+            assert_eq!(reads(devtools, "doubled"), ["use_count", "count"]);
+
+            set_count.set(2);
+            set_use_count.set(false);
+            assert_eq!(label.get_untracked(), "0");
+            set_use_count.set(true);
+            assert_eq!(label.get_untracked(), "4");
+            assert_eq!(reads(devtools, "label"), ["doubled"]);
+            set_use_count.set(false);
+            assert_eq!(label.get_untracked(), "0");
+            assert_eq!(reads(devtools, "doubled"), ["use_count"]);
+            assert_eq!(node(devtools, "doubled").runs.get_untracked(), 4);
+        });
+    }
+
+    #[test]
+    fn lists_named_signals_with_their_readers() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let devtools = Devtools::provide();
+            let (count, set_count) = signal(1);
+            let (unread, _) = signal(0);
+            devtools.name("count", count);
+            devtools.name("unread", unread);
+            let odd = Memo::new(devtools.trace_memo("odd", move |_| count.get() % 2 == 1));
+            let text =
+                Memo::new(devtools.trace_memo(
+                    "text",
+                    move |_| {
+                        if odd.get() {
+                            "odd"
+                        } else {
+                            "even"
+                        }
+                    },
+                ));
+
+            assert_eq!(text.get_untracked(), "odd");
+            assert_eq!(node(devtools, "count").kind, NodeKind::Signal);
+            assert_eq!(read_by(devtools, "count"), ["odd"]);
+            assert_eq!(read_by(devtools, "odd"), ["text"]);
+            assert!(read_by(devtools, "unread").is_empty());
+            assert!(read_by(devtools, "text").is_empty());
+
+            set_count.set(2);
+            assert_eq!(text.get_untracked(), "even");
+            assert_eq!(read_by(devtools, "count"), ["odd"]);
+        });
+    }
+
+    #[test]
+    fn forgets_nodes_whose_owner_is_gone() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let devtools = Devtools::provide();
+            let child = Owner::new();
+            child.with(|| {
+                let (temporary, _) = signal(0);
+                devtools.name("temporary", temporary);
+                Memo::new(devtools.trace_memo("doubled", move |_| temporary.get() * 2)).get()
+            });
+            assert_eq!(devtools.nodes.with_untracked(Vec::len), 2);
+            assert_eq!(devtools.names.with_value(HashMap::len), 2);
+
+            child.cleanup();
+            assert_eq!(devtools.nodes.with_untracked(Vec::len), 0);
+            assert_eq!(devtools.names.with_value(HashMap::len), 0);
+        });
+    }
+}
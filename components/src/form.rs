@@ -0,0 +1,500 @@
+//! Form state with validation, see [`FormState`] and [`Field`].
+//!
+//! A field keeps what is typed into its input as text and parses it into its type `T` when asked,
+//! so a number input can hold "12a" for a moment without the field losing track of it.
+//! The validators run on the parsed value, the error they return is what [`FieldError`] shows.
+
+use std::{fmt, future::Future, ops::RangeInclusive, pin::Pin, str::FromStr, sync::Arc};
+
+use leptos::{prelude::*, task::spawn_local};
+use regex::Regex;
+
+type Check<T> = Arc<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+/// A synchronous check of a field's value, see [`required`], [`min_len`], [`max_len`],
+/// [`pattern`] and [`range`].
+pub struct Validator<T>(Check<T>);
+
+impl<T> Clone for Validator<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Validator<T> {
+    /// A check of your own, `f` returns the error message if the value is not valid.
+    pub fn new(f: impl Fn(&T) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+/// The text can't be empty, spaces don't count.
+pub fn required() -> Validator<String> {
+    Validator::new(|text: &String| {
+        if text.trim().is_empty() {
+            Err("is required".to_owned())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// The text is at least `min` characters long.
+pub fn min_len(min: usize) -> Validator<String> {
+    Validator::new(move |text: &String| {
+        if text.chars().count() < min {
+            Err(format!("needs at least {min} characters"))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// The text is at most `max` characters long.
+pub fn max_len(max: usize) -> Validator<String> {
+    Validator::new(move |text: &String| {
+        if text.chars().count() > max {
+            Err(format!("can't be longer than {max} characters"))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// The text matches the regular expression `regex`, otherwise the error is `message`.
+///
+/// # Panics
+/// Panics if `regex` is not a valid regular expression.
+pub fn pattern(regex: &str, message: &'static str) -> Validator<String> {
+    let regex = Regex::new(regex).expect("the pattern to be a valid regular expression");
+    Validator::new(move |text: &String| {
+        if regex.is_match(text) {
+            Ok(())
+        } else {
+            Err(message.to_owned())
+        }
+    })
+}
+
+/// The number is inside `range`.
+pub fn range<N>(range: RangeInclusive<N>) -> Validator<N>
+where
+    N: PartialOrd + fmt::Display + Send + Sync + 'static,
+{
+    Validator::new(move |n: &N| {
+        if range.contains(n) {
+            Ok(())
+        } else {
+            Err(format!(
+                "must be between {} and {}",
+                range.start(),
+                range.end()
+            ))
+        }
+    })
+}
+
+type AsyncCheck<T> =
+    Arc<dyn Fn(T) -> Pin<Box<dyn Future<Output = Result<(), String>>>> + Send + Sync>;
+
+/// One input of a form. Like a signal it is `Copy`, so the view and the event handlers can share it.
+pub struct Field<T: Send + Sync + 'static> {
+    text: RwSignal<String>,
+    initial: StoredValue<String>,
+    touched: RwSignal<bool>,
+    validators: StoredValue<Vec<Validator<T>>>,
+    async_validators: StoredValue<Vec<AsyncCheck<T>>>,
+    /// What the async validators said about the current text, `None` while they are running.
+    async_result: RwSignal<Option<Result<(), String>>>,
+    /// Counts the changes, so an async check that finishes late doesn't overwrite a newer one.
+    version: StoredValue<u64>,
+}
+
+impl<T: Send + Sync + 'static> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for Field<T> {}
+
+impl<T> Field<T>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+{
+    /// A field that starts out with `initial` in its input. A field on its own doesn't need a form,
+    /// but [`FormState::field`] creates one that the form knows about.
+    pub fn new(initial: impl ToString) -> Self {
+        let initial = initial.to_string();
+        Self {
+            text: RwSignal::new(initial.clone()),
+            initial: StoredValue::new(initial),
+            touched: RwSignal::new(false),
+            validators: StoredValue::new(Vec::new()),
+            async_validators: StoredValue::new(Vec::new()),
+            async_result: RwSignal::new(Some(Ok(()))),
+            version: StoredValue::new(0),
+        }
+    }
+
+    /// Adds a validator, they run in the order they were added and the first error wins.
+    pub fn validate(self, validator: Validator<T>) -> Self {
+        self.validators
+            .update_value(|validators| validators.push(validator));
+        self
+    }
+
+    /// Adds a validator that takes its time, like asking a server. It only runs when the text
+    /// changes and the synchronous validators are happy with it.
+    pub fn validate_async<F, Fut>(self, f: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        let check: AsyncCheck<T> = Arc::new(move |value| Box::pin(f(value)));
+        self.async_validators
+            .update_value(|validators| validators.push(check));
+        self
+    }
+
+    /// What is in the input right now.
+    pub fn text(&self) -> String {
+        self.text.get()
+    }
+
+    /// Sets what is in the input, call it from the input's event handler.
+    pub fn set_text(&self, text: impl Into<String>) {
+        self.text.set(text.into());
+        self.check_async();
+    }
+
+    /// The parsed value, if the text parses at all. Validators are not asked.
+    pub fn value(&self) -> Option<T> {
+        self.text.with(|text| text.parse().ok())
+    }
+
+    /// Why the value is not valid, the async validators only count once they are done.
+    pub fn error(&self) -> Option<String> {
+        let value = match self.text.with(|text| text.parse::<T>()) {
+            Ok(value) => value,
+            Err(_) => return Some(format!("\"{}\" is not a valid value", self.text.get())),
+        };
+        self.validators
+            .with_value(|validators| {
+                validators
+                    .iter()
+                    .find_map(|validator| (validator.0)(&value).err())
+            })
+            .or_else(|| self.async_result.get().and_then(Result::err))
+    }
+
+    /// The error, but only once the user is done with the field, so nobody gets told off
+    /// for an empty input they haven't typed into yet.
+    pub fn visible_error(&self) -> Option<String> {
+        self.touched.get().then(|| self.error()).flatten()
+    }
+
+    /// Whether the value passes every validator. Not valid while an async validator is still running.
+    pub fn is_valid(&self) -> bool {
+        self.error().is_none() && !self.is_pending()
+    }
+
+    /// Whether an async validator is still running.
+    pub fn is_pending(&self) -> bool {
+        self.async_result.with(Option::is_none)
+    }
+
+    /// Whether the user is done with the field at least once, see [`Field::touch`].
+    pub fn is_touched(&self) -> bool {
+        self.touched.get()
+    }
+
+    /// Marks the field as touched, usually when the input loses focus.
+    pub fn touch(&self) {
+        self.touched.set(true);
+    }
+
+    /// Whether the text is different from the one the field started out with.
+    pub fn is_dirty(&self) -> bool {
+        self.text
+            .with(|text| self.initial.with_value(|initial| text != initial))
+    }
+
+    /// Back to the initial text, untouched.
+    pub fn reset(&self) {
+        self.text.set(self.initial.get_value());
+        self.touched.set(false);
+        self.check_async();
+    }
+
+    fn check_async(&self) {
+        let checks = self.async_validators.get_value();
+        if checks.is_empty() {
+            return;
+        }
+        self.version.update_value(|version| *version += 1);
+        let version = self.version.get_value();
+
+        let synchronously_valid = self.text.with_untracked(|text| {
+            text.parse::<T>().ok().filter(|value| {
+                self.validators.with_value(|validators| {
+                    validators
+                        .iter()
+                        .all(|validator| (validator.0)(value).is_ok())
+                })
+            })
+        });
+        let Some(value) = synchronously_valid else {
+            // the synchronous error is shown already
+            self.async_result.set(Some(Ok(())));
+            return;
+        };
+
+        self.async_result.set(None);
+        let field = *self;
+        spawn_local(async move {
+            let mut result = Ok(());
+            for check in checks {
+                result = check(value.clone()).await;
+                if result.is_err() {
+                    break;
+                }
+            }
+            // only the newest check gets to say something
+            if field.version.try_get_value() == Some(version) {
+                field.async_result.set(Some(result));
+            }
+        });
+    }
+}
+
+/// What a [`FormState`] needs from its fields, whatever their type.
+trait AnyField: Send + Sync {
+    fn is_valid(&self) -> bool;
+    fn is_dirty(&self) -> bool;
+    fn touch(&self);
+    fn reset(&self);
+}
+
+impl<T> AnyField for Field<T>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+{
+    fn is_valid(&self) -> bool {
+        Field::is_valid(self)
+    }
+
+    fn is_dirty(&self) -> bool {
+        Field::is_dirty(self)
+    }
+
+    fn touch(&self) {
+        Field::touch(self)
+    }
+
+    fn reset(&self) {
+        Field::reset(self)
+    }
+}
+
+/// The fields of one form, so they can be checked, touched and reset together. `Copy`.
+#[derive(Clone, Copy)]
+pub struct FormState {
+    fields: StoredValue<Vec<Box<dyn AnyField>>>,
+}
+
+impl Default for FormState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormState {
+    pub fn new() -> Self {
+        Self {
+            fields: StoredValue::new(Vec::new()),
+        }
+    }
+
+    /// Creates a [`Field`] that belongs to this form.
+    pub fn field<T>(&self, initial: impl ToString) -> Field<T>
+    where
+        T: FromStr + Clone + Send + Sync + 'static,
+    {
+        let field = Field::new(initial);
+        self.fields
+            .update_value(|fields| fields.push(Box::new(field)));
+        field
+    }
+
+    /// Whether every field is valid.
+    pub fn is_valid(&self) -> bool {
+        self.fields
+            .with_value(|fields| fields.iter().all(|field| field.is_valid()))
+    }
+
+    /// Whether any field was changed.
+    pub fn is_dirty(&self) -> bool {
+        self.fields
+            .with_value(|fields| fields.iter().any(|field| field.is_dirty()))
+    }
+
+    /// Touches every field, so all the errors show up, and tells whether the form is valid.
+    /// Call it when the form is submitted.
+    pub fn submit(&self) -> bool {
+        self.fields.with_value(|fields| {
+            for field in fields {
+                field.touch();
+            }
+        });
+        self.is_valid()
+    }
+
+    pub fn reset(&self) {
+        self.fields.with_value(|fields| {
+            for field in fields {
+                field.reset();
+            }
+        });
+    }
+}
+
+/// The error of a field, shown beside its input once the field was touched.
+#[component]
+pub fn FieldError<T>(field: Field<T>) -> impl IntoView
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+{
+    move || {
+        if field.is_pending() {
+            Some(view! { <span class="field-pending">"checking..."</span> }.into_any())
+        } else {
+            field
+                .visible_error()
+                .map(|error| view! { <span class="field-error">{error}</span> }.into_any())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_first_failing_validator_wins() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let name = Field::<String>::new("")
+                .validate(required())
+                .validate(min_len(2))
+                .validate(max_len(4))
+                .validate(pattern("^[a-z]*$", "only lowercase letters"));
+
+            assert_eq!(name.error().as_deref(), Some("is required"));
+            name.set_text("a");
+            assert_eq!(name.error().as_deref(), Some("needs at least 2 characters"));
+            name.set_text("abcde");
+            assert_eq!(
+                name.error().as_deref(),
+                Some("can't be longer than 4 characters")
+            );
+            name.set_text("Ab");
+            assert_eq!(name.error().as_deref(), Some("only lowercase letters"));
+            name.set_text("ab");
+            assert!(name.is_valid());
+        });
+    }
+
+    #[test]
+    fn typed_fields_parse_before_they_validate() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let age = Field::<u8>::new(30).validate(range(1..=120));
+
+            assert_eq!(age.value(), Some(30));
+            age.set_text("12a");
+            assert_eq!(age.value(), None);
+            assert_eq!(age.error().as_deref(), Some("\"12a\" is not a valid value"));
+            age.set_text("121");
+            assert_eq!(age.error().as_deref(), Some("must be between 1 and 120"));
+        });
+    }
+
+    #[test]
+    fn errors_show_once_touched() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let form = FormState::new();
+            let name = form.field::<String>("").validate(required());
+
+            assert_eq!(name.visible_error(), None);
+            assert!(!form.submit());
+            assert_eq!(name.visible_error().as_deref(), Some("is required"));
+        });
+    }
+
+    #[test]
+    fn tracks_dirty_and_resets() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let form = FormState::new();
+            let name = form.field::<String>("Leo");
+
+            assert!(!form.is_dirty());
+            name.set_text("Ada");
+            name.touch();
+            assert!(form.is_dirty());
+
+            form.reset();
+            assert_eq!(name.text(), "Leo");
+            assert!(!name.is_touched());
+            assert!(!form.is_dirty());
+        });
+    }
+
+    #[test]
+    fn async_validation_keeps_the_newest_result() {
+        use futures::channel::oneshot;
+        use leptos::task::Executor;
+        use std::{collections::VecDeque, sync::Mutex};
+
+        // no other test here sets an executor, `poll_local` runs whatever was spawned so far
+        _ = Executor::init_futures_executor();
+        let answers = Arc::new(Mutex::new(VecDeque::new()));
+        let owner = Owner::new();
+        owner.with(|| {
+            let pending = answers.clone();
+            let name = Field::<String>::new("")
+                .validate(required())
+                .validate_async(move |_| {
+                    let answer = pending.lock().unwrap().pop_front();
+                    async move {
+                        let answer: oneshot::Receiver<Result<(), String>> = answer.unwrap();
+                        answer.await.unwrap()
+                    }
+                });
+            let ask = || {
+                let (send, receive) = oneshot::channel();
+                answers.lock().unwrap().push_back(receive);
+                send
+            };
+
+            let first = ask();
+            name.set_text("admin");
+            let second = ask();
+            name.set_text("bob");
+            assert!(name.is_pending());
+            assert!(!name.is_valid());
+
+            second.send(Ok(())).unwrap();
+            Executor::poll_local();
+            assert!(name.is_valid());
+
+            first.send(Err("is taken".into())).unwrap();
+            Executor::poll_local();
+            assert!(!name.is_pending());
+            assert!(name.is_valid());
+            assert_eq!(name.error(), None);
+        });
+    }
+}
@@ -0,0 +1,22 @@
+//! Components that more than one lesson uses.
+//!
+//! Every lesson is its own binary crate, so anything that would otherwise get copy-pasted
+//! between them lives here instead and is pulled in through the workspace dependency.
+
+mod auth;
+mod devtools;
+pub mod form;
+mod logger;
+mod sleep;
+pub mod storage;
+mod virtual_list;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use auth::{use_session, LoginError, LoginForm, Session};
+pub use devtools::{Devtools, DevtoolsPanel};
+pub use form::{Field, FieldError, FormState};
+pub use logger::{use_log, Entry, Level, Log, LogConsole, Logger};
+pub use sleep::sleep;
+pub use virtual_list::{RowHeight, VirtualList};
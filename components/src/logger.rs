@@ -0,0 +1,320 @@
+//! A log that the lessons can show on the page, see [`LogConsole`].
+//!
+//! Every entry knows its [`Level`], when it was written and which component wrote it.
+//! The log keeps only the newest entries, so a lesson that logs on every keystroke can't grow it forever.
+
+use std::{collections::VecDeque, fmt};
+
+use leptos::prelude::*;
+use serde::Serialize;
+
+/// How important an entry is. The console can hide everything below a level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    const ALL: [Level; 4] = [Level::Debug, Level::Info, Level::Warn, Level::Error];
+
+    fn label(self) -> &'static str {
+        match self {
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        }
+    }
+}
+
+/// One line of the log.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Entry {
+    pub level: Level,
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: f64,
+    /// The component that wrote it.
+    pub source: &'static str,
+    pub message: String,
+}
+
+impl Entry {
+    /// Whether the entry passes the filters of the console, the text filter ignores case.
+    fn matches(&self, min_level: Level, text: &str) -> bool {
+        let text = text.to_lowercase();
+        self.level >= min_level
+            && (self.message.to_lowercase().contains(&text)
+                || self.source.to_lowercase().contains(&text))
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the time of day in UTC, that's enough to tell entries apart
+        let ms = self.timestamp as u64;
+        let (h, m, s) = (ms / 3_600_000 % 24, ms / 60_000 % 60, ms / 1000 % 60);
+        write!(
+            f,
+            "[{h:02}:{m:02}:{s:02}.{:03}] {:<5} {}: {}",
+            ms % 1000,
+            self.level.label(),
+            self.source,
+            self.message
+        )
+    }
+}
+
+/// The newest `capacity` entries, oldest first.
+#[derive(Debug, Clone)]
+struct RingBuffer {
+    entries: VecDeque<Entry>,
+    capacity: usize,
+}
+
+impl RingBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn push(&mut self, entry: Entry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+}
+
+/// The log itself. `Copy`, so it goes through context, see [`Logger::provide`] and [`use_log`].
+#[derive(Clone, Copy)]
+pub struct Logger {
+    buffer: RwSignal<RingBuffer>,
+}
+
+impl Logger {
+    /// Creates a log that keeps the newest `capacity` entries and provides it through context.
+    pub fn provide(capacity: usize) -> Self {
+        let logger = Self {
+            buffer: RwSignal::new(RingBuffer::new(capacity)),
+        };
+        provide_context(logger);
+        logger
+    }
+
+    /// A handle that writes to this log in the name of `source`.
+    pub fn source(self, source: &'static str) -> Log {
+        Log {
+            logger: self,
+            source,
+        }
+    }
+
+    pub fn clear(self) {
+        self.buffer.update(|buffer| buffer.entries.clear());
+    }
+
+    /// All the entries, oldest first, as a JSON array.
+    pub fn to_json(self) -> String {
+        self.buffer
+            .with_untracked(|buffer| serde_json::to_string_pretty(&buffer.entries))
+            .expect("entries to serialize")
+    }
+}
+
+/// Writes to a [`Logger`] in the name of one component. `Copy`, so event handlers can take it along.
+#[derive(Clone, Copy)]
+pub struct Log {
+    logger: Logger,
+    source: &'static str,
+}
+
+impl Log {
+    pub fn log(self, level: Level, message: impl fmt::Display) {
+        let entry = Entry {
+            level,
+            timestamp: now(),
+            source: self.source,
+            message: message.to_string(),
+        };
+        self.logger.buffer.update(|buffer| buffer.push(entry));
+    }
+}
+
+/// Grabs the log that was provided with [`Logger::provide`], to write to it in the name of `source`.
+/// Do this while the component is being set up: event handlers run outside of the reactive owner,
+/// so they can't reach the context themselves.
+pub fn use_log(source: &'static str) -> Log {
+    use_context::<Logger>()
+        .expect("the log to have been provided")
+        .source(source)
+}
+
+/// Writes a formatted message to a [`Log`], like `log!` does to the browser console.
+/// The level is optional and defaults to `Info`.
+///
+/// ```ignore
+/// log_to!(log, "{first} {last}");
+/// log_to!(log, Warn, "{name} is taken");
+/// ```
+#[macro_export]
+macro_rules! log_to {
+    ($log:expr, $level:ident, $($arg:tt)+) => {
+        $log.log($crate::Level::$level, format!($($arg)+))
+    };
+    ($log:expr, $($arg:tt)+) => {
+        $log.log($crate::Level::Info, format!($($arg)+))
+    };
+}
+
+#[cfg(target_arch = "wasm32")]
+fn now() -> f64 {
+    leptos::web_sys::js_sys::Date::now()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |since| since.as_secs_f64() * 1000.0)
+}
+
+/// `text` escaped for the data part of a `data:` URL.
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Shows the entries of the provided [`Logger`], newest last,
+/// with filters for the level and the text, and a way to get them out as JSON.
+#[component]
+pub fn LogConsole() -> impl IntoView {
+    let logger = use_context::<Logger>().expect("the log to have been provided");
+    let (min_level, set_min_level) = signal(Level::Debug);
+    let (filter, set_filter) = signal(String::new());
+
+    let lines = move || {
+        let filter = filter.read();
+        logger.buffer.with(|buffer| {
+            buffer
+                .entries
+                .iter()
+                .filter(|entry| entry.matches(min_level.get(), &filter))
+                .map(|entry| view! { <div class=entry.level.label()>{entry.to_string()}</div> })
+                .collect_view()
+        })
+    };
+    // the whole log, not only what the filters let through
+    let export_href = move || {
+        logger.buffer.track();
+        format!(
+            "data:application/json;charset=utf-8,{}",
+            percent_encode(&logger.to_json())
+        )
+    };
+    let copy = move |_| {
+        // nothing to do if it fails, the export link still works
+        _ = window()
+            .navigator()
+            .clipboard()
+            .write_text(&logger.to_json());
+    };
+
+    view! {
+        <div class="log-console">
+            <div class="log-controls">
+                <select on:change=move |ev| {
+                    let level = event_target_value(&ev);
+                    if let Some(level) = Level::ALL.into_iter().find(|l| l.label() == level) {
+                        set_min_level.set(level);
+                    }
+                }>
+                    {Level::ALL
+                        .into_iter()
+                        .map(|level| view! { <option value=level.label()>{level.label()}</option> })
+                        .collect_view()}
+                </select>
+                <input
+                    type="search"
+                    placeholder="Filter"
+                    prop:value=filter
+                    on:input=move |ev| set_filter.set(event_target_value(&ev))
+                />
+                <button on:click=copy>"Copy JSON"</button>
+                <a download="log.json" href=export_href>"Export JSON"</a>
+                <button on:click=move |_| logger.clear()>"Clear"</button>
+            </div>
+            <pre class="output">{lines}</pre>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: Level, message: &str) -> Entry {
+        Entry {
+            level,
+            timestamp: 0.0,
+            source: "Test",
+            message: message.to_owned(),
+        }
+    }
+
+    #[test]
+    fn drops_the_oldest_entries_when_full() {
+        let mut buffer = RingBuffer::new(2);
+        for message in ["a", "b", "c"] {
+            buffer.push(entry(Level::Info, message));
+        }
+
+        let messages: Vec<_> = buffer.entries.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, ["b", "c"]);
+    }
+
+    #[test]
+    fn filters_by_level_and_text() {
+        let warning = entry(Level::Warn, "Name is taken");
+
+        assert!(warning.matches(Level::Info, ""));
+        assert!(warning.matches(Level::Warn, "TAKEN"));
+        assert!(warning.matches(Level::Debug, "test"));
+        assert!(!warning.matches(Level::Error, ""));
+        assert!(!warning.matches(Level::Debug, "free"));
+    }
+
+    #[test]
+    fn formats_the_time_of_day() {
+        let entry = Entry {
+            timestamp: 45_296_789.0,
+            ..entry(Level::Info, "hi")
+        };
+
+        assert_eq!(entry.to_string(), "[12:34:56.789] INFO  Test: hi");
+    }
+
+    #[test]
+    fn exports_json() {
+        let json = serde_json::to_string(&entry(Level::Error, "oops")).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"level":"Error","timestamp":0.0,"source":"Test","message":"oops"}"#
+        );
+        assert_eq!(percent_encode("{\"a\": 1}"), "%7B%22a%22%3A%201%7D");
+    }
+}
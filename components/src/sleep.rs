@@ -0,0 +1,20 @@
+//! A timer for the lessons that pretend to wait for an API, see [`sleep`].
+
+use std::time::Duration;
+
+/// Waits for `duration`.
+///
+/// The future is `Send`, so a `Resource` can run it. A browser timeout isn't, so the timeout only
+/// wakes the future through a channel. Outside the browser, like in the snapshot tests and on the
+/// server, a thread does the waiting.
+pub async fn sleep(duration: Duration) {
+    let (tx, rx) = futures::channel::oneshot::channel();
+    #[cfg(target_arch = "wasm32")]
+    leptos::prelude::set_timeout(move || _ = tx.send(()), duration);
+    #[cfg(not(target_arch = "wasm32"))]
+    std::thread::spawn(move || {
+        std::thread::sleep(duration);
+        _ = tx.send(());
+    });
+    _ = rx.await;
+}
@@ -0,0 +1,53 @@
+//! Values kept in the local storage of the browser, as JSON, so they survive a reload.
+//!
+//! Outside the browser there is no storage, e.g. in the snapshot tests: nothing is loaded
+//! and nothing is saved, the lessons start from their defaults like on a first visit.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+fn local_storage() -> Option<web_sys::Storage> {
+    // there is no `window` outside the browser
+    if cfg!(target_arch = "wasm32") {
+        web_sys::window()?.local_storage().ok()?
+    } else {
+        None
+    }
+}
+
+/// The value saved under `key`, if there is one that can be read back.
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    parse(&local_storage()?.get_item(key).ok()??)
+}
+
+/// Saves `value` under `key`. A full or disabled storage only means the value isn't kept,
+/// the page keeps working.
+pub fn save<T: Serialize>(key: &str, value: &T) {
+    if let (Some(storage), Ok(json)) = (local_storage(), serde_json::to_string(value)) {
+        _ = storage.set_item(key, &json);
+    }
+}
+
+/// Reads back what [`save`] wrote. Anything we can't read back (an older format, a hand edited
+/// value) counts as nothing saved.
+pub fn parse<T: DeserializeOwned>(json: &str) -> Option<T> {
+    serde_json::from_str(json).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nothing_is_kept_outside_the_browser() {
+        save("storage_test", &3);
+
+        assert_eq!(load::<i32>("storage_test"), None);
+    }
+
+    #[test]
+    fn unreadable_values_count_as_nothing_saved() {
+        assert_eq!(parse::<Vec<i32>>("[1, 2]"), Some(vec![1, 2]));
+        assert_eq!(parse::<Vec<i32>>("[1, \"two\"]"), None);
+        assert_eq!(parse::<Vec<i32>>(""), None);
+    }
+}
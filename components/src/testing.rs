@@ -0,0 +1,208 @@
+//! Helpers for the tests of the lessons.
+//!
+//! The browser tests mount a component into the real DOM, poke at it like a user would
+//! and then look at the HTML it ended up with.
+//! The snapshot tests render a lesson to HTML on the native target instead, no browser needed,
+//! either as it first shows up or once its resources have loaded.
+
+use futures::StreamExt;
+use leptos::{
+    prelude::*,
+    task::{
+        any_spawner::{PinnedFuture, PinnedLocalFuture},
+        CustomExecutor, Executor,
+    },
+};
+use wasm_bindgen::JsCast;
+use web_sys::{
+    DataTransfer, DragEvent, DragEventInit, EventTarget, HtmlButtonElement, HtmlElement,
+    HtmlFormElement, HtmlInputElement, KeyboardEvent, KeyboardEventInit,
+};
+
+/// Mounts the view into a fresh `<div>` at the end of the `<body>`, so the tests don't see each other.
+pub fn mount<F, N>(f: F) -> HtmlElement
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView,
+{
+    let root = document()
+        .create_element("div")
+        .expect("could not create the test root")
+        .unchecked_into::<HtmlElement>();
+    document()
+        .body()
+        .expect("page to have a body")
+        .append_child(&root)
+        .expect("could not add the test root");
+    // the view should live as long as the page, so the handle is never unmounted
+    leptos::mount::mount_to(root.clone(), f).forget();
+    root
+}
+
+/// Every element in `root` that matches the CSS `selector`.
+pub fn query_all<T: JsCast>(root: &HtmlElement, selector: &str) -> Vec<T> {
+    let nodes = root
+        .query_selector_all(selector)
+        .expect("selector to be valid");
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .map(|node| node.unchecked_into())
+        .collect()
+}
+
+/// How many elements in `root` match the CSS `selector`.
+pub fn count(root: &HtmlElement, selector: &str) -> usize {
+    query_all::<web_sys::Element>(root, selector).len()
+}
+
+/// The text of the first element in `root` that matches the CSS `selector`.
+pub fn text(root: &HtmlElement, selector: &str) -> String {
+    root.query_selector(selector)
+        .expect("selector to be valid")
+        .and_then(|element| element.text_content())
+        .unwrap_or_default()
+}
+
+/// The text of every element in `root` that matches the CSS `selector`, in page order.
+pub fn texts(root: &HtmlElement, selector: &str) -> Vec<String> {
+    query_all::<web_sys::Element>(root, selector)
+        .into_iter()
+        .map(|element| element.text_content().unwrap_or_default())
+        .collect()
+}
+
+/// Clicks the first button in `root` that reads `label`.
+pub fn click(root: &HtmlElement, label: &str) {
+    query_all::<HtmlButtonElement>(root, "button")
+        .into_iter()
+        .find(|button| button.text_content().as_deref() == Some(label))
+        .unwrap_or_else(|| panic!("no button reads {label:?}"))
+        .click();
+}
+
+/// Types `value` into the first input in `root` that matches the CSS `selector`.
+pub fn type_into(root: &HtmlElement, selector: &str, value: &str) {
+    let input = query_all::<HtmlInputElement>(root, selector)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| panic!("no input matches {selector:?}"));
+    input.set_value(value);
+    let event = web_sys::Event::new("input").expect("could not create the input event");
+    input
+        .dispatch_event(&event)
+        .expect("could not dispatch the input event");
+}
+
+/// Submits the first form in `root` that matches the CSS `selector`, like pressing Enter in one of its inputs.
+pub fn submit(root: &HtmlElement, selector: &str) {
+    first(root, selector)
+        .unchecked_into::<HtmlFormElement>()
+        .request_submit()
+        .expect("could not submit the form");
+}
+
+/// Presses `keys` on the first element in `root` that matches the CSS `selector`.
+/// `keys` is a key name with optional modifiers in front, like `"Enter"` or `"Alt+ArrowUp"`.
+pub fn press(root: &HtmlElement, selector: &str, keys: &str) {
+    let element = first(root, selector);
+    let (modifiers, key) = keys.rsplit_once('+').unwrap_or(("", keys));
+    let init = KeyboardEventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    init.set_key(key);
+    for modifier in modifiers.split('+').filter(|modifier| !modifier.is_empty()) {
+        match modifier {
+            "Alt" => init.set_alt_key(true),
+            "Ctrl" => init.set_ctrl_key(true),
+            "Meta" => init.set_meta_key(true),
+            "Shift" => init.set_shift_key(true),
+            other => panic!("unknown modifier {other:?}"),
+        }
+    }
+    let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init)
+        .expect("could not create the keydown event");
+    element
+        .dispatch_event(&event)
+        .expect("could not dispatch the keydown event");
+}
+
+/// Drags the first element that matches `from` and drops it on the first one that matches `to`,
+/// with the same events a browser fires for a mouse drag.
+pub fn drag_and_drop(root: &HtmlElement, from: &str, to: &str) {
+    let from = first(root, from);
+    let to = first(root, to);
+    let data = DataTransfer::new().expect("could not create the drag data");
+    let fire = |target: &EventTarget, kind: &str| {
+        let init = DragEventInit::new();
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+        init.set_data_transfer(Some(&data));
+        let event = DragEvent::new_with_event_init_dict(kind, &init)
+            .expect("could not create the drag event");
+        target
+            .dispatch_event(&event)
+            .expect("could not dispatch the drag event");
+    };
+    fire(&from, "dragstart");
+    fire(&to, "dragenter");
+    fire(&to, "dragover");
+    fire(&to, "drop");
+    fire(&from, "dragend");
+}
+
+fn first(root: &HtmlElement, selector: &str) -> web_sys::Element {
+    root.query_selector(selector)
+        .expect("selector to be valid")
+        .unwrap_or_else(|| panic!("nothing matches {selector:?}"))
+}
+
+/// An executor that drops everything it is given, see [`render_to_string`].
+struct NoopExecutor;
+
+impl CustomExecutor for NoopExecutor {
+    fn spawn(&self, _fut: PinnedFuture<()>) {}
+    fn spawn_local(&self, _fut: PinnedLocalFuture<()>) {}
+    fn poll_local(&self) {}
+}
+
+/// Renders the view to an HTML string on the native target, for the snapshot tests.
+///
+/// Only the first render ends up in the HTML: effects and async work never run,
+/// so a `<Suspense/>` shows its fallback and nothing touches the browser.
+pub fn render_to_string<F, N>(f: F) -> String
+where
+    F: FnOnce() -> N,
+    N: IntoView,
+{
+    // an error only means the executor was already set by an earlier test
+    _ = Executor::init_custom_executor(NoopExecutor);
+
+    let owner = Owner::new();
+    let html = owner.with(|| f().to_html());
+    owner.unset();
+    html
+}
+
+/// Renders the view to an HTML string on the native target once the resources it reads have
+/// loaded, for the snapshot tests of the async lessons.
+///
+/// The view is rendered inside a `<Suspense/>`, which waits for every `Resource` read under it.
+/// A `LocalResource` never loads outside the browser, the `<Suspense/>` it is read in shows its
+/// fallback. The futures run on a thread pool, so a test binary uses either this or
+/// [`render_to_string`], whichever sets its executor first wins.
+pub fn render_resolved<F, N>(f: F) -> String
+where
+    F: FnOnce() -> N + Send + 'static,
+    N: IntoView + 'static,
+{
+    // an error only means the executor was already set by an earlier test
+    _ = Executor::init_futures_executor();
+
+    let owner = Owner::new();
+    let html = owner.with(|| {
+        let stream = view! { <Suspense>{f()}</Suspense> }.to_html_stream_in_order();
+        futures::executor::block_on(stream.collect::<String>())
+    });
+    owner.unset();
+    html
+}
@@ -0,0 +1,255 @@
+use std::{collections::HashMap, hash::Hash, ops::Range};
+
+use leptos::{html::Div, prelude::*, wasm_bindgen::JsCast, web_sys::HtmlElement};
+
+/// How tall the rows of a [`VirtualList`] are.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowHeight {
+    /// Every row is exactly this many pixels tall.
+    Fixed(f64),
+    /// Rows start out as `estimate` pixels tall, once a row is on the page the list measures it
+    /// and keeps its height under its key, so rows can differ and keep their height when the list
+    /// is sorted or filtered. Each row should render a single element.
+    Measured { estimate: f64 },
+}
+
+impl RowHeight {
+    fn initial(self) -> f64 {
+        match self {
+            RowHeight::Fixed(height) | RowHeight::Measured { estimate: height } => height,
+        }
+    }
+}
+
+/// Like `<For/>`, but only the rows that are scrolled into view (plus `overscan` rows on both
+/// sides) are in the DOM, so a list can have many thousands of rows and stay responsive.
+///
+/// The list is a scrolling box of `height` pixels. A spacer as tall as all the rows together
+/// keeps the scroll bar honest, and the visible rows are moved down to where the first of them starts.
+#[component]
+pub fn VirtualList<IF, I, T, KF, K, VF, V>(
+    /// Items over which the list should iterate, same as `each` of `<For/>`.
+    each: IF,
+    /// A key function that will be applied to each item, same as `key` of `<For/>`.
+    key: KF,
+    /// A function that takes the item, and returns the view of its row.
+    view: VF,
+    /// Height of the rows.
+    #[prop(default = RowHeight::Measured { estimate: 24.0 })]
+    row_height: RowHeight,
+    /// Height of the scrolling box in pixels.
+    #[prop(default = 400.0)]
+    height: f64,
+    /// How many rows to render above and below the visible ones, so fast scrolling doesn't show gaps.
+    #[prop(default = 5)]
+    overscan: usize,
+) -> impl IntoView
+where
+    IF: Fn() -> I + Send + Sync + 'static,
+    I: IntoIterator<Item = T>,
+    T: Clone + Send + Sync + 'static,
+    KF: Fn(&T) -> K + Send + Sync + Clone + 'static,
+    K: Eq + Hash + Send + Sync + 'static,
+    VF: Fn(T) -> V + Send + Clone + 'static,
+    V: IntoView + 'static,
+{
+    // the items are collected once per change of `each`, scrolling only slices them.
+    // `T` doesn't have to be `PartialEq`, so every new list counts as a change
+    let items =
+        Memo::new_with_compare(move |_| each().into_iter().collect::<Vec<_>>(), |_, _| true);
+    let (scroll_top, set_scroll_top) = signal(0.0);
+    // the measured heights by key, rows that weren't on the page yet count with the estimate
+    let (measured, set_measured) = signal(HashMap::<K, f64>::new());
+    let estimate = row_height.initial();
+
+    let offsets = Memo::new({
+        let key = key.clone();
+        move |_| {
+            items.with(|items| {
+                measured.with(|measured| row_offsets(row_heights(items, &key, measured, estimate)))
+            })
+        }
+    });
+    let range = Memo::new(move |_| {
+        offsets.with(|offsets| visible_range(offsets, scroll_top.get(), height, overscan))
+    });
+
+    let rows = NodeRef::<Div>::new();
+    if let RowHeight::Measured { .. } = row_height {
+        let key = key.clone();
+        Effect::new(move |_| {
+            let keys = items.with(|items| items[range.get()].iter().map(&key).collect::<Vec<_>>());
+            let Some(rows) = rows.get() else {
+                return;
+            };
+            let mut row = rows.first_element_child();
+            let mut heights = Vec::new();
+            while let Some(element) = row {
+                heights.push(element.unchecked_ref::<HtmlElement>().offset_height() as f64);
+                row = element.next_element_sibling();
+            }
+            set_measured.maybe_update(|measured| measure(measured, keys.into_iter().zip(heights)));
+        });
+    }
+
+    let on_scroll = move |ev| {
+        set_scroll_top.set(event_target::<HtmlElement>(&ev).scroll_top() as f64);
+    };
+
+    view! {
+        <div style:overflow-y="auto" style:height=format!("{height}px") on:scroll=on_scroll>
+            <div
+                style:position="relative"
+                style:height=move || format!("{}px", offsets.with(|offsets| offsets[offsets.len() - 1]))
+            >
+                <div
+                    node_ref=rows
+                    style:position="absolute"
+                    style:width="100%"
+                    style:transform=move || format!("translateY({}px)", offsets.with(|offsets| offsets[range.get().start]))
+                >
+                    <For
+                        each=move || items.with(|items| items[range.get()].to_vec())
+                        key=key
+                        children=view
+                    />
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// The height of every row, as far as it was measured.
+fn row_heights<'a, T, K>(
+    items: &'a [T],
+    key: &'a impl Fn(&T) -> K,
+    measured: &'a HashMap<K, f64>,
+    estimate: f64,
+) -> impl Iterator<Item = f64> + 'a
+where
+    K: Eq + Hash,
+{
+    items
+        .iter()
+        .map(move |item| measured.get(&key(item)).copied().unwrap_or(estimate))
+}
+
+/// Notes down the heights of the rendered rows, returns whether any of them changed.
+fn measure<K: Eq + Hash>(
+    measured: &mut HashMap<K, f64>,
+    rows: impl Iterator<Item = (K, f64)>,
+) -> bool {
+    let mut changed = false;
+    for (key, height) in rows {
+        // rounding on the page moves this by fractions of a pixel, which isn't worth
+        // another render, and a row that isn't laid out yet has no height at all
+        if height <= 0.0 {
+            continue;
+        }
+        if measured
+            .get(&key)
+            .is_none_or(|old| (height - old).abs() > 0.5)
+        {
+            measured.insert(key, height);
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Where every row starts, from the row heights, followed by where the last one ends.
+fn row_offsets(heights: impl Iterator<Item = f64>) -> Vec<f64> {
+    let mut offsets = vec![0.0];
+    let mut top = 0.0;
+    for height in heights {
+        top += height;
+        offsets.push(top);
+    }
+    offsets
+}
+
+/// Indices of the rows that should be rendered for the given scroll position,
+/// `offsets` as made by [`row_offsets`].
+fn visible_range(
+    offsets: &[f64],
+    scroll_top: f64,
+    viewport_height: f64,
+    overscan: usize,
+) -> Range<usize> {
+    let len = offsets.len() - 1;
+    let scroll_top = scroll_top.max(0.0);
+    // the first row that ends below the top of the viewport,
+    // and the first one that starts below its bottom
+    let first = offsets[1..].partition_point(|&end| end <= scroll_top);
+    let after = offsets[..len].partition_point(|&start| start < scroll_top + viewport_height);
+
+    first.saturating_sub(overscan)..(after + overscan).min(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn even_rows(len: usize, height: f64) -> Vec<f64> {
+        row_offsets((0..len).map(|_| height))
+    }
+
+    #[test]
+    fn renders_the_viewport_and_the_overscan() {
+        let offsets = even_rows(1000, 10.0);
+        assert_eq!(visible_range(&offsets, 0.0, 100.0, 5), 0..15);
+        assert_eq!(visible_range(&offsets, 500.0, 100.0, 5), 45..65);
+        assert_eq!(visible_range(&offsets, 505.0, 100.0, 0), 50..61);
+    }
+
+    #[test]
+    fn stays_inside_the_list() {
+        let offsets = even_rows(1000, 10.0);
+        assert_eq!(visible_range(&offsets, 9_950.0, 100.0, 5), 990..1000);
+        assert_eq!(visible_range(&offsets, 50_000.0, 100.0, 5), 995..1000);
+        assert_eq!(visible_range(&even_rows(3, 10.0), 0.0, 100.0, 5), 0..3);
+        assert_eq!(visible_range(&even_rows(0, 10.0), 0.0, 100.0, 5), 0..0);
+    }
+
+    #[test]
+    fn measured_rows_keep_their_height_when_reordered() {
+        let key = |name: &&str| name.to_string();
+        let mut measured = HashMap::new();
+        let rendered = [("short", 10.0), ("tall", 50.0), ("unlaid", 0.0)];
+        assert!(measure(
+            &mut measured,
+            rendered
+                .map(|(name, height)| (name.to_owned(), height))
+                .into_iter()
+        ));
+        // measuring the same heights again is no change
+        assert!(!measure(
+            &mut measured,
+            [("tall".to_owned(), 50.2)].into_iter()
+        ));
+
+        let offsets = |items: &[&str]| row_offsets(row_heights(items, &key, &measured, 20.0));
+        assert_eq!(
+            offsets(&["short", "tall", "unlaid", "new"]),
+            [0.0, 10.0, 60.0, 80.0, 100.0]
+        );
+        // sorted the other way around, the tall row is still tall
+        assert_eq!(
+            offsets(&["new", "unlaid", "tall", "short"]),
+            [0.0, 20.0, 40.0, 90.0, 100.0]
+        );
+        // and filtered
+        assert_eq!(offsets(&["tall"]), [0.0, 50.0]);
+    }
+
+    #[test]
+    fn places_rows_of_different_heights() {
+        let offsets = row_offsets([10.0, 50.0, 10.0, 30.0, 10.0, 10.0].into_iter());
+        assert_eq!(offsets, [0.0, 10.0, 60.0, 70.0, 100.0, 110.0, 120.0]);
+
+        // the tall second row covers most of the viewport on its own
+        assert_eq!(visible_range(&offsets, 15.0, 40.0, 0), 1..2);
+        assert_eq!(visible_range(&offsets, 65.0, 40.0, 0), 2..5);
+        assert_eq!(visible_range(&offsets, 65.0, 40.0, 1), 1..6);
+    }
+}
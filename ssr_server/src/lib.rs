@@ -0,0 +1,140 @@
+//! A small axum server for the lessons that can be rendered on the server.
+//!
+//! Every request that isn't for a file in the `pkg` folder is rendered by the lesson's `shell`,
+//! the browser then hydrates that HTML with the wasm bundle found in `pkg`.
+//! Requests under `/api` are handed to the lesson's `#[server]` functions.
+//! A `<Redirect/>` while rendering turns into an HTTP redirect,
+//! and a page can pick the status code of the response through [`ResponseStatus`].
+//! The lesson itself has to be built with the `ssr` feature, this crate only wires it to axum.
+
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use any_spawner::Executor;
+use axum::{
+    http::{StatusCode, Uri},
+    response::{Html, IntoResponse, Redirect, Response},
+    routing::post,
+    Router,
+};
+use futures::StreamExt;
+use hydration_context::{SharedContext, SsrSharedContext};
+use leptos::{config::LeptosOptions, prelude::*};
+use leptos_router::{components::provide_server_redirect, location::RequestUrl};
+use server_fn::axum::handle_server_fn;
+use tower_http::services::ServeDir;
+
+/// The status code of the response, in context while a page is rendered.
+/// Pages that answer with something else than `200 OK`, like a 404, set it.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseStatus(Arc<Mutex<Option<StatusCode>>>);
+
+impl ResponseStatus {
+    /// Sets the status code, anything that isn't a valid one is ignored.
+    pub fn set(&self, status: u16) {
+        if let Ok(status) = StatusCode::from_u16(status) {
+            *self.0.lock().expect("status lock to not be poisoned") = Some(status);
+        }
+    }
+
+    fn get(&self) -> StatusCode {
+        self.0
+            .lock()
+            .expect("status lock to not be poisoned")
+            .unwrap_or(StatusCode::OK)
+    }
+}
+
+/// What [`render`] made of a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    pub html: String,
+    pub status: StatusCode,
+    /// Where the app sent the request with `<Redirect/>`, if it did.
+    pub redirect: Option<String>,
+}
+
+impl IntoResponse for Page {
+    fn into_response(self) -> Response {
+        match self.redirect {
+            Some(path) => Redirect::to(&path).into_response(),
+            None => (self.status, Html(self.html)).into_response(),
+        }
+    }
+}
+
+/// Renders `app` the way it would look at `url`, including the data the browser needs to hydrate it.
+/// It has to run inside a tokio runtime, the resources are spawned on it.
+pub async fn render<F, IV>(url: &str, app: F) -> Page
+where
+    F: FnOnce() -> IV,
+    IV: IntoView,
+{
+    // resources spawn their futures on whatever executor leptos was given
+    _ = Executor::init_tokio();
+
+    // the shared context marks the HTML for hydration and collects the serialized resources
+    let shared_context: Arc<dyn SharedContext + Send + Sync> = Arc::new(SsrSharedContext::new());
+    let owner = Owner::new_root(Some(Arc::clone(&shared_context)));
+    let redirect = Arc::new(Mutex::new(None));
+    let status = ResponseStatus::default();
+
+    let stream = owner.with(|| {
+        // the `<Router/>` reads the location from here instead of the browser
+        provide_context(RequestUrl::new(url));
+        let redirect = Arc::clone(&redirect);
+        provide_server_redirect(move |path| {
+            *redirect.lock().expect("redirect lock to not be poisoned") = Some(path.to_owned());
+        });
+        provide_context(status.clone());
+        app().to_html_stream_in_order()
+    });
+    let mut html = stream.collect::<String>().await;
+
+    if let Some(data) = shared_context.pending_data() {
+        let scripts = data.map(|chunk| format!("<script>{chunk}</script>"));
+        html.push_str(&scripts.collect::<String>().await);
+    }
+
+    owner.unset();
+    let redirect = redirect
+        .lock()
+        .expect("redirect lock to not be poisoned")
+        .take();
+    Page {
+        html,
+        status: status.get(),
+        redirect,
+    }
+}
+
+/// Serves the `shell` of a lesson on `options.site_addr`, until the process is stopped.
+pub async fn serve<IV>(options: LeptosOptions, shell: fn(LeptosOptions) -> IV)
+where
+    IV: IntoView + 'static,
+{
+    let addr = options.site_addr;
+    let pkg_dir = Path::new(&*options.site_root).join(&*options.site_pkg_dir);
+    let app = Router::new()
+        // `#[server]` functions register themselves under `/api` by default
+        .route(
+            "/api/*fn_name",
+            post(handle_server_fn).get(handle_server_fn),
+        )
+        .nest_service(
+            &format!("/{}", options.site_pkg_dir),
+            ServeDir::new(pkg_dir),
+        )
+        .fallback(move |uri: Uri| {
+            let options = options.clone();
+            async move { render(&uri.to_string(), move || shell(options)).await }
+        });
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .expect("could not bind the server address");
+    println!("listening on http://{addr}");
+    axum::serve(listener, app).await.expect("server error");
+}
@@ -0,0 +1,414 @@
+//! `#[derive(Routable)]`, see the `typed_routes` crate for how to use it.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Data, DeriveInput, Error, Expr, Fields, Ident, LitStr, Path, Token, Type, Variant,
+};
+
+#[proc_macro_derive(Routable, attributes(route, nested))]
+pub fn derive_routable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// One segment of the path of a route.
+enum Segment {
+    Static(String),
+    /// `:name`, the field of the same name.
+    Param(Ident),
+    /// `*name`, the field of the same name gets the rest of the path.
+    Wildcard(Ident),
+}
+
+fn parse_segments(path: &LitStr) -> syn::Result<Vec<Segment>> {
+    let value = path.value();
+    value
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            let name = |name: &str| {
+                syn::parse_str::<Ident>(name).map_err(|_| {
+                    Error::new(
+                        path.span(),
+                        format!("`{name}` can't be the name of a field"),
+                    )
+                })
+            };
+            Ok(match segment.as_bytes()[0] {
+                b':' => Segment::Param(name(&segment[1..])?),
+                b'*' => Segment::Wildcard(name(&segment[1..])?),
+                _ => Segment::Static(segment.to_owned()),
+            })
+        })
+        .collect()
+}
+
+/// `name = value` in `#[route(...)]`.
+struct Prop {
+    name: Ident,
+    value: Expr,
+}
+
+impl Parse for Prop {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(Self { name, value })
+    }
+}
+
+/// `#[route("/path", view = Page, component = GuardedRoute, other_prop = value)]`
+struct RouteAttr {
+    path: LitStr,
+    view: Expr,
+    component: Option<Path>,
+    /// Whatever else the route component takes.
+    props: Vec<Prop>,
+}
+
+impl Parse for RouteAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path: LitStr = input.parse()?;
+        let mut view = None;
+        let mut component = None;
+        let mut props = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            for prop in Punctuated::<Prop, Token![,]>::parse_terminated(input)? {
+                match prop.name.to_string().as_str() {
+                    "view" => view = Some(prop.value),
+                    "component" => match prop.value {
+                        Expr::Path(expr) => component = Some(expr.path),
+                        value => return Err(Error::new(value.span(), "expected a component")),
+                    },
+                    _ => props.push(prop),
+                }
+            }
+        }
+        let view = view.ok_or_else(|| Error::new(path.span(), "expected `view = ...`"))?;
+        Ok(Self {
+            path,
+            view,
+            component,
+            props,
+        })
+    }
+}
+
+/// A variant of the enum, one route.
+struct Route<'a> {
+    variant: &'a Variant,
+    attr: RouteAttr,
+    segments: Vec<Segment>,
+    /// The `#[nested]` field, whose routes are the children of this one.
+    nested: Option<(&'a Ident, &'a Type)>,
+}
+
+impl<'a> Route<'a> {
+    fn new(variant: &'a Variant) -> syn::Result<Self> {
+        let attr = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("route"))
+            .ok_or_else(|| {
+                Error::new(variant.span(), "expected `#[route(\"/path\", view = ...)]`")
+            })?
+            .parse_args::<RouteAttr>()?;
+        let segments = parse_segments(&attr.path)?;
+
+        let fields = match &variant.fields {
+            Fields::Named(fields) => fields.named.iter().collect(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(fields) => {
+                return Err(Error::new(
+                    fields.span(),
+                    "the params of a route need names",
+                ))
+            }
+        };
+        let mut nested = None;
+        for field in fields {
+            let name = field.ident.as_ref().expect("named fields to have a name");
+            if field
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("nested"))
+            {
+                if nested.replace((name, &field.ty)).is_some() {
+                    return Err(Error::new(
+                        name.span(),
+                        "a route can only have one `#[nested]` field",
+                    ));
+                }
+            } else if !segments.iter().any(|segment| segment.field() == Some(name)) {
+                return Err(Error::new(
+                    name.span(),
+                    format!("`{name}` is neither a param of the path nor `#[nested]`"),
+                ));
+            }
+        }
+        for name in segments.iter().filter_map(Segment::field) {
+            if !variant
+                .fields
+                .iter()
+                .any(|field| field.ident.as_ref() == Some(name))
+            {
+                return Err(Error::new(
+                    attr.path.span(),
+                    format!("expected a field `{name}` for the param of the same name"),
+                ));
+            }
+        }
+        if nested.is_some() && segments.iter().any(|s| matches!(s, Segment::Wildcard(_))) {
+            return Err(Error::new(
+                attr.path.span(),
+                "a wildcard leaves nothing for `#[nested]` routes",
+            ));
+        }
+
+        Ok(Self {
+            variant,
+            attr,
+            segments,
+            nested,
+        })
+    }
+
+    /// `Self::Variant { a, b }` or `Self::Variant`, as a pattern and as an expression.
+    fn constructor(&self, ty: &Ident) -> TokenStream2 {
+        let variant = &self.variant.ident;
+        match &self.variant.fields {
+            Fields::Unit => quote!(#ty::#variant),
+            fields => {
+                let names = fields.iter().map(|field| &field.ident);
+                quote!(#ty::#variant { #(#names),* })
+            }
+        }
+    }
+
+    /// The route definition, for `<Routes/>` or a `<ParentRoute/>`.
+    fn definition(&self) -> TokenStream2 {
+        let RouteAttr {
+            path,
+            view,
+            component,
+            props,
+        } = &self.attr;
+        let component = match (component, &self.nested) {
+            (Some(component), _) => quote!(#component),
+            (None, None) => quote!(::typed_routes::__private::leptos_router::components::Route),
+            (None, Some(_)) => {
+                quote!(::typed_routes::__private::leptos_router::components::ParentRoute)
+            }
+        };
+        let props = props
+            .iter()
+            .map(|Prop { name, value }| quote!(.#name(#value)));
+        let children = self.nested.map(|(_, ty)| {
+            quote! {
+                .children(::typed_routes::__private::leptos::children::ToChildren::to_children(
+                    || <#ty as ::typed_routes::Routable>::routes(),
+                ))
+            }
+        });
+
+        quote! {
+            #component(
+                ::typed_routes::__private::leptos::component::component_props_builder(&#component)
+                    .path(::typed_routes::__private::leptos_router::path!(#path))
+                    .view(#view)
+                    #(#props)*
+                    #children
+                    .build(),
+            )
+        }
+    }
+
+    /// The arm of `append_path`.
+    fn append_arm(&self, ty: &Ident) -> TokenStream2 {
+        let pattern = self.constructor(ty);
+        let segments = self.segments.iter().map(|segment| match segment {
+            Segment::Static(segment) => {
+                let segment = format!("/{segment}");
+                quote!(path.push_str(#segment);)
+            }
+            Segment::Param(name) => quote! {
+                path.push('/');
+                path.push_str(&::typed_routes::__private::escape(&::std::string::ToString::to_string(#name)));
+            },
+            Segment::Wildcard(name) => quote! {
+                for part in ::std::string::ToString::to_string(#name).split('/').filter(|part| !part.is_empty()) {
+                    path.push('/');
+                    path.push_str(&::typed_routes::__private::escape(part));
+                }
+            },
+        });
+        let nested = self
+            .nested
+            .map(|(name, ty)| quote!(<#ty as ::typed_routes::Routable>::append_path(#name, path);));
+
+        quote! {
+            #pattern => {
+                #(#segments)*
+                #nested
+            }
+        }
+    }
+
+    /// A function that matches the segments to this route alone. Like the router, it only looks at the
+    /// shape of the path to pick the route, and only then parses the params.
+    fn match_fn(&self, ty: &Ident, name: &Ident) -> TokenStream2 {
+        let raw = |name: &Ident| format_ident!("__raw_{name}");
+        let shape = self.segments.iter().map(|segment| match segment {
+            Segment::Static(segment) => quote! {
+                let (first, rest) = rest.split_first()?;
+                if *first != #segment {
+                    return None;
+                }
+            },
+            Segment::Param(name) => {
+                let raw = raw(name);
+                quote!(let (#raw, rest) = rest.split_first()?;)
+            }
+            Segment::Wildcard(name) => {
+                let raw = raw(name);
+                quote! {
+                    let #raw = rest;
+                    let rest: &[&str] = &[];
+                }
+            }
+        });
+        let rest = match self.nested {
+            Some((name, ty)) => {
+                let raw = raw(name);
+                quote!(let #raw = <#ty as ::typed_routes::Routable>::match_segments(rest)?;)
+            }
+            None => quote! {
+                if !rest.is_empty() {
+                    return None;
+                }
+            },
+        };
+        let params = self.segments.iter().filter_map(|segment| match segment {
+            Segment::Static(_) => None,
+            Segment::Param(name) => {
+                let (raw, param) = (raw(name), name.to_string());
+                Some(quote!(let #name = ::typed_routes::__private::parse_param(#param, #raw);))
+            }
+            Segment::Wildcard(name) => {
+                let (raw, param) = (raw(name), name.to_string());
+                Some(quote!(let #name = ::typed_routes::__private::parse_wildcard(#param, #raw);))
+            }
+        });
+        let nested = self.nested.map(|(name, _)| {
+            let raw = raw(name);
+            quote!(let #name = #raw;)
+        });
+        // a param that doesn't parse is the answer, the other routes aren't tried
+        let unwrap = self.variant.fields.iter().map(|field| {
+            let name = &field.ident;
+            quote! {
+                let #name = match #name {
+                    Ok(value) => value,
+                    Err(error) => return Some(Err(error)),
+                };
+            }
+        });
+        let constructor = self.constructor(ty);
+
+        quote! {
+            fn #name(
+                segments: &[&str],
+            ) -> Option<Result<#ty, ::typed_routes::ParamError>> {
+                let rest = segments;
+                #(#shape)*
+                #rest
+                #(#params)*
+                #nested
+                #(#unwrap)*
+                Some(Ok(#constructor))
+            }
+        }
+    }
+}
+
+impl Segment {
+    fn field(&self) -> Option<&Ident> {
+        match self {
+            Segment::Static(_) => None,
+            Segment::Param(name) | Segment::Wildcard(name) => Some(name),
+        }
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ty = &input.ident;
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(input.span(), "only an enum can be `Routable`"));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "a `Routable` enum can't be generic",
+        ));
+    }
+    let routes = data
+        .variants
+        .iter()
+        .map(Route::new)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let definitions = routes.iter().map(Route::definition);
+    let append_arms = routes.iter().map(|route| route.append_arm(ty));
+    let match_names: Vec<_> = (0..routes.len())
+        .map(|i| format_ident!("__match_route_{i}"))
+        .collect();
+    let match_fns = routes
+        .iter()
+        .zip(&match_names)
+        .map(|(route, name)| route.match_fn(ty, name));
+    // an enum without variants can't be matched
+    let append_body = if routes.is_empty() {
+        quote!(match *self {})
+    } else {
+        quote!(match self { #(#append_arms)* })
+    };
+
+    Ok(quote! {
+        impl ::typed_routes::Routable for #ty {
+            fn routes() -> impl ::typed_routes::__private::leptos_router::MatchNestedRoutes
+                + ::std::clone::Clone
+                + ::std::marker::Send
+                + 'static {
+                (#(#definitions,)*)
+            }
+
+            fn append_path(&self, path: &mut ::std::string::String) {
+                #append_body
+            }
+
+            fn match_segments(
+                segments: &[&str],
+            ) -> ::std::option::Option<::std::result::Result<Self, ::typed_routes::ParamError>> {
+                #(#match_fns)*
+                // the first route that matches wins, in the order of the variants like in `<Routes/>`
+                ::std::option::Option::None #(.or_else(|| #match_names(segments)))*
+            }
+        }
+
+        impl ::typed_routes::__private::leptos_router::components::ToHref for #ty {
+            fn to_href(&self) -> ::std::boxed::Box<dyn Fn() -> ::std::string::String + '_> {
+                let path = ::typed_routes::Routable::to_path(self);
+                ::std::boxed::Box::new(move || path.clone())
+            }
+        }
+    })
+}
@@ -0,0 +1,351 @@
+//! Routes as an enum instead of path strings, see [`Routable`].
+//!
+//! Every variant of the enum is a route, with its path and its page in a `#[route]` attribute
+//! and its params as fields. `#[derive(Routable)]` writes the `<Routes/>` tree, the links to a
+//! route and the parsing of the params, so a link to a route that doesn't exist doesn't compile.
+//!
+//! ```ignore
+//! #[derive(Routable, Debug, Clone, PartialEq)]
+//! enum AppRoute {
+//!     #[route("/", view = Home)]
+//!     Home,
+//!     // `:id` is parsed into the field of the same name
+//!     #[route("/users/:id", view = User)]
+//!     User { id: u32 },
+//!     // the routes of a `#[nested]` field are the children of this one
+//!     #[route("/settings", view = Settings)]
+//!     Settings {
+//!         #[nested]
+//!         tab: SettingsTab,
+//!     },
+//!     // everything but `path` and `view` goes to the route component, `<Route/>` by default
+//!     #[route("/account", view = Account, component = GuardedRoute)]
+//!     Account,
+//! }
+//!
+//! view! {
+//!     <TypedRoutes routes=AppRoute::routes fallback=|| "Not found."/>
+//! }
+//! view! { <A href=AppRoute::User { id: 7 }>"User 7"</A> }
+//! ```
+
+use std::fmt;
+
+use leptos::{children::ToChildren, prelude::*};
+use leptos_router::{
+    components::{Routes, RoutesProps},
+    hooks::use_location,
+    MatchNestedRoutes,
+};
+
+// the derive names `typed_routes::...` in the code it writes, which has to work in the tests too
+extern crate self as typed_routes;
+
+pub use typed_routes_derive::Routable;
+
+/// A route enum, implement it with `#[derive(Routable)]`.
+pub trait Routable: Sized {
+    /// The routes of every variant, for `<Routes/>` or a `<ParentRoute/>`.
+    fn routes() -> impl MatchNestedRoutes + Clone + Send + 'static;
+
+    /// Appends the segments of this route to `path`, each with its leading `/`.
+    fn append_path(&self, path: &mut String);
+
+    /// The route that the router shows for these path segments, `None` where it shows the fallback.
+    ///
+    /// Like the router, it picks the first route whose path has the shape of the segments.
+    /// Only then are the params parsed, a param that doesn't parse is an error and not a reason
+    /// to try the next route: for `/users/seven` the router shows `/users/:id` all the same.
+    fn match_segments(segments: &[&str]) -> Option<Result<Self, ParamError>>;
+
+    /// The path of this route, a link to it.
+    fn to_path(&self) -> String {
+        let mut path = String::new();
+        self.append_path(&mut path);
+        if path.is_empty() {
+            path.push('/');
+        }
+        path
+    }
+
+    /// The route that `path` leads to, the query string and the fragment are ignored.
+    /// `None` where no route leads, or where the params of the route don't parse.
+    fn from_path(path: &str) -> Option<Self> {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        Self::match_segments(&segments)?.ok()
+    }
+}
+
+/// A param of the route that the router shows doesn't parse into the type of its field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamError {
+    /// The name of the param, like `id` for `:id`.
+    pub param: &'static str,
+    /// What the path has in its place, with the escapes undone where they make UTF-8.
+    pub value: String,
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` isn't a valid `{}`", self.value, self.param)
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// `<Routes/>` with the routes of a [`Routable`] enum, `view!` can't take them as children.
+#[component(transparent)]
+pub fn TypedRoutes<Defs, F, V>(
+    /// `Routable::routes` of the enum, like `AppRoute::routes`.
+    routes: fn() -> Defs,
+    /// Shown when none of the routes match.
+    fallback: F,
+) -> impl IntoView
+where
+    Defs: MatchNestedRoutes + Clone + Send + 'static,
+    F: FnOnce() -> V + Clone + Send + 'static,
+    V: IntoView + 'static,
+{
+    Routes(
+        RoutesProps::builder()
+            .fallback(fallback)
+            .children(ToChildren::to_children(routes))
+            .build(),
+    )
+}
+
+/// The current route, `None` where none of the routes of `R` lead or where its params don't parse.
+pub fn use_route<R>() -> Memo<Option<R>>
+where
+    R: Routable + PartialEq + Send + Sync + 'static,
+{
+    let pathname = use_location().pathname;
+    Memo::new(move |_| pathname.with(|path| R::from_path(path)))
+}
+
+/// What the derive uses, not meant to be called by hand.
+#[doc(hidden)]
+pub mod __private {
+    use std::str::FromStr;
+
+    pub use leptos;
+    pub use leptos_router;
+
+    use crate::ParamError;
+
+    /// The `:param` in `segment`, parsed.
+    pub fn parse_param<T: FromStr>(param: &'static str, segment: &str) -> Result<T, ParamError> {
+        let error = || ParamError {
+            param,
+            value: unescape(segment).unwrap_or_else(|| segment.to_owned()),
+        };
+        unescape(segment)
+            .ok_or_else(error)?
+            .parse()
+            .map_err(|_| error())
+    }
+
+    /// The `*param` that gets the rest of the path, `parts` are its segments.
+    pub fn parse_wildcard<T: FromStr>(
+        param: &'static str,
+        parts: &[&str],
+    ) -> Result<T, ParamError> {
+        let error = || ParamError {
+            param,
+            value: parts.join("/"),
+        };
+        parts
+            .iter()
+            .map(|part| unescape(part))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(error)?
+            .join("/")
+            .parse()
+            .map_err(|_| error())
+    }
+
+    /// `value` escaped to be one segment of a path.
+    pub fn escape(value: &str) -> String {
+        value
+            .bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    (byte as char).to_string()
+                }
+                _ => format!("%{byte:02X}"),
+            })
+            .collect()
+    }
+
+    /// A segment of a path with its escapes undone, `None` if they don't make UTF-8.
+    pub fn unescape(segment: &str) -> Option<String> {
+        let bytes = segment.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let byte = match bytes[i] {
+                b'%' => {
+                    let hex = segment.get(i + 1..i + 3)?;
+                    i += 2;
+                    u8::from_str_radix(hex, 16).ok()?
+                }
+                byte => byte,
+            };
+            decoded.push(byte);
+            i += 1;
+        }
+        String::from_utf8(decoded).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos_router::MatchParams;
+
+    use super::*;
+
+    #[derive(Routable, Debug, Clone, PartialEq)]
+    enum AppRoute {
+        #[route("/", view = || "home")]
+        Home,
+        #[route("/users/:id", view = || "user")]
+        User { id: u32 },
+        #[route("/contacts", view = || "contacts")]
+        Contacts {
+            #[nested]
+            contact: ContactRoute,
+        },
+        #[route("/*any", view = || "not found")]
+        NotFound { any: String },
+    }
+
+    #[derive(Routable, Debug, Clone, PartialEq)]
+    enum ContactRoute {
+        #[route(":name/messages", view = || "messages")]
+        Messages { name: String },
+        #[route("", view = || "no contact")]
+        Index,
+    }
+
+    #[test]
+    fn builds_the_paths() {
+        assert_eq!(AppRoute::Home.to_path(), "/");
+        assert_eq!(AppRoute::User { id: 7 }.to_path(), "/users/7");
+        let messages = AppRoute::Contacts {
+            contact: ContactRoute::Messages {
+                name: "Bob Bobson".to_owned(),
+            },
+        };
+        assert_eq!(messages.to_path(), "/contacts/Bob%20Bobson/messages");
+        let index = AppRoute::Contacts {
+            contact: ContactRoute::Index,
+        };
+        assert_eq!(index.to_path(), "/contacts");
+    }
+
+    #[test]
+    fn parses_the_params() {
+        assert_eq!(
+            AppRoute::from_path("/users/7?tab=1"),
+            Some(AppRoute::User { id: 7 })
+        );
+        assert_eq!(
+            AppRoute::from_path("/contacts/Bob%20Bobson/messages/"),
+            Some(AppRoute::Contacts {
+                contact: ContactRoute::Messages {
+                    name: "Bob Bobson".to_owned()
+                }
+            })
+        );
+        // the router shows `/users/:id`, its param just doesn't parse
+        assert_eq!(
+            AppRoute::match_segments(&["users", "seven"]),
+            Some(Err(ParamError {
+                param: "id",
+                value: "seven".to_owned()
+            }))
+        );
+        assert_eq!(AppRoute::from_path("/users/seven"), None);
+        assert_eq!(ContactRoute::from_path("/bob"), None);
+    }
+
+    #[test]
+    fn round_trips_through_the_path() {
+        let route = AppRoute::NotFound {
+            any: "a/b c".to_owned(),
+        };
+        assert_eq!(AppRoute::from_path(&route.to_path()), Some(route));
+        assert_eq!(__private::unescape("%E2%9C%93"), Some("✓".to_owned()));
+        assert_eq!(__private::unescape("%G1"), None);
+    }
+
+    /// The route that the router itself picks for `path`, as the params it hands to the page.
+    fn router_params(path: &str) -> Option<Vec<(String, String)>> {
+        let routes = AppRoute::routes();
+        let (matched, _) = routes.match_nested(path);
+        matched.map(|(_, matched)| {
+            matched
+                .to_params()
+                .into_iter()
+                .map(|(name, value)| (name.into_owned(), value))
+                .collect()
+        })
+    }
+
+    fn params(route: &[(&str, &str)]) -> Option<Vec<(String, String)>> {
+        Some(
+            route
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn agrees_with_the_router() {
+        let owner = Owner::new();
+        owner.with(|| {
+            assert_eq!(router_params("/"), params(&[]));
+            assert_eq!(AppRoute::from_path("/"), Some(AppRoute::Home));
+
+            assert_eq!(router_params("/users/7"), params(&[("id", "7")]));
+            assert_eq!(
+                AppRoute::from_path("/users/7"),
+                Some(AppRoute::User { id: 7 })
+            );
+
+            // the same route for both, even though the param doesn't parse
+            assert_eq!(router_params("/users/seven"), params(&[("id", "seven")]));
+            assert!(matches!(
+                AppRoute::match_segments(&["users", "seven"]),
+                Some(Err(ParamError { param: "id", .. }))
+            ));
+
+            assert_eq!(
+                router_params("/contacts/bob/messages"),
+                params(&[("name", "bob")])
+            );
+            assert_eq!(
+                AppRoute::from_path("/contacts/bob/messages"),
+                Some(AppRoute::Contacts {
+                    contact: ContactRoute::Messages {
+                        name: "bob".to_owned()
+                    }
+                })
+            );
+
+            // none of the nested routes match, so both go on to the wildcard
+            assert_eq!(
+                router_params("/contacts/bob"),
+                params(&[("any", "contacts/bob")])
+            );
+            assert_eq!(
+                AppRoute::from_path("/contacts/bob"),
+                Some(AppRoute::NotFound {
+                    any: "contacts/bob".to_owned()
+                })
+            );
+        });
+    }
+}